@@ -0,0 +1,233 @@
+use crate::error::GraphError;
+use crate::traversal::{DepthFirstEvents, DfsEvent};
+use crate::visit::{
+    GraphBase, GraphProp, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable, Visitable,
+};
+
+pub type NodePair<G> = (<G as GraphBase>::NodeId, <G as GraphBase>::NodeId);
+pub type Component<G> = Vec<NodePair<G>>;
+
+struct Analysis {
+    bridges: Vec<(usize, usize)>,
+    articulation_points: Vec<usize>,
+    components: Vec<Vec<(usize, usize)>>,
+}
+
+fn analyse<G>(graph: G) -> Result<Analysis, GraphError>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + Visitable + GraphProp,
+{
+    if graph.is_directed() {
+        return Err(GraphError::DirectedGraph);
+    }
+
+    let size = graph.node_bound();
+    let mut order = vec![0; size];
+    let mut low = vec![0; size];
+    let mut parents = vec![None; size];
+    let mut children = vec![0; size];
+    let mut articulation = vec![false; size];
+    let mut stack = Vec::new();
+    let mut analysis = Analysis {
+        bridges: Vec::new(),
+        articulation_points: Vec::new(),
+        components: Vec::new(),
+    };
+    let mut counter = 0;
+
+    let mut events = match graph.node_identifiers().next() {
+        Some(node) => DepthFirstEvents::new(graph, node),
+        None => return Ok(analysis),
+    };
+
+    for start in graph.node_identifiers() {
+        events.move_to(start);
+
+        for event in events.by_ref() {
+            match event {
+                DfsEvent::Discover(v) => {
+                    let v = graph.to_index(v);
+                    order[v] = counter;
+                    low[v] = counter;
+                    counter += 1;
+                }
+                DfsEvent::TreeEdge(u, v) => {
+                    let (u, v) = (graph.to_index(u), graph.to_index(v));
+                    parents[v] = Some(u);
+                    children[u] += 1;
+                    stack.push((u, v));
+                }
+                DfsEvent::BackEdge(u, v) if u != v => {
+                    let (u, v) = (graph.to_index(u), graph.to_index(v));
+                    low[u] = low[u].min(order[v]);
+                    stack.push((u, v));
+                }
+                DfsEvent::Finish(v) => {
+                    let v = graph.to_index(v);
+                    let u = match parents[v] {
+                        Some(u) => u,
+                        None => continue,
+                    };
+                    low[u] = low[u].min(low[v]);
+
+                    if low[v] > order[u] {
+                        analysis.bridges.push((u, v));
+                    }
+
+                    if low[v] >= order[u] {
+                        if parents[u].is_some() || children[u] > 1 {
+                            articulation[u] = true;
+                        }
+
+                        let mut component = Vec::new();
+                        while let Some(edge) = stack.pop() {
+                            component.push(edge);
+                            if edge == (u, v) {
+                                break;
+                            }
+                        }
+                        analysis.components.push(component);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    analysis.articulation_points = (0..size).filter(|&v| articulation[v]).collect();
+
+    Ok(analysis)
+}
+
+pub fn bridges<G>(graph: G) -> Result<Vec<NodePair<G>>, GraphError>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + Visitable + GraphProp,
+{
+    Ok(analyse(graph)?
+        .bridges
+        .into_iter()
+        .map(|(a, b)| (graph.to_node(a), graph.to_node(b)))
+        .collect())
+}
+
+pub fn articulation_points<G>(graph: G) -> Result<Vec<G::NodeId>, GraphError>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + Visitable + GraphProp,
+{
+    Ok(analyse(graph)?
+        .articulation_points
+        .into_iter()
+        .map(|v| graph.to_node(v))
+        .collect())
+}
+
+pub fn biconnected_components<G>(graph: G) -> Result<Vec<Component<G>>, GraphError>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + Visitable + GraphProp,
+{
+    Ok(analyse(graph)?
+        .components
+        .into_iter()
+        .map(|component| {
+            component
+                .into_iter()
+                .map(|(a, b)| (graph.to_node(a), graph.to_node(b)))
+                .collect()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Graph;
+
+    // two triangles joined by the bridge 2-3, with a pendant node 6
+    fn test_graph() -> Graph<(), ()> {
+        let mut g = Graph::<(), ()>::new_undirected();
+        for _ in 0..7 {
+            g.add_node(());
+        }
+        for (a, b) in [
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (5, 6),
+        ] {
+            g.set_edge(a, b, ());
+        }
+
+        g
+    }
+
+    fn pairs(edges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let mut pairs: Vec<(usize, usize)> =
+            edges.iter().map(|&(a, b)| (a.min(b), a.max(b))).collect();
+        pairs.sort();
+        pairs
+    }
+
+    #[test]
+    fn can_find_bridges() {
+        let g = test_graph();
+        assert_eq!(pairs(&bridges(&g).unwrap()), vec![(2, 3), (5, 6)]);
+    }
+
+    #[test]
+    fn can_find_articulation_points() {
+        let g = test_graph();
+        assert_eq!(articulation_points(&g).unwrap(), vec![2, 3, 5]);
+
+        let mut star = Graph::<(), ()>::new_undirected();
+        for _ in 0..4 {
+            star.add_node(());
+        }
+        star.set_edge(0, 1, ());
+        star.set_edge(0, 2, ());
+        star.set_edge(0, 3, ());
+        assert_eq!(articulation_points(&star).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn can_find_biconnected_components() {
+        let g = test_graph();
+        let mut components: Vec<Vec<(usize, usize)>> = biconnected_components(&g)
+            .unwrap()
+            .iter()
+            .map(|c| pairs(c))
+            .collect();
+        components.sort();
+
+        assert_eq!(
+            components,
+            vec![
+                vec![(0, 1), (0, 2), (1, 2)],
+                vec![(2, 3)],
+                vec![(3, 4), (3, 5), (4, 5)],
+                vec![(5, 6)],
+            ]
+        );
+    }
+
+    #[test]
+    fn can_reject_directed_graph() {
+        let mut g = Graph::<(), ()>::new_directed();
+        g.add_node(());
+        g.add_node(());
+        g.set_edge(0, 1, ());
+
+        assert_eq!(bridges(&g).unwrap_err(), GraphError::DirectedGraph);
+        assert_eq!(
+            articulation_points(&g).unwrap_err(),
+            GraphError::DirectedGraph
+        );
+        assert_eq!(
+            biconnected_components(&g).unwrap_err(),
+            GraphError::DirectedGraph
+        );
+    }
+}
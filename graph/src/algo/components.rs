@@ -0,0 +1,271 @@
+use crate::graph::Graph;
+use crate::list::AdjacencyList;
+use crate::traversal::{DepthFirstEvents, DfsEvent};
+use crate::unionfind::DisjointSet;
+use crate::visit::{GraphProp, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable, Visitable};
+
+pub fn connected_components<G>(graph: G) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut present = vec![false; graph.node_bound()];
+    let mut sets = DisjointSet::new(graph.node_bound());
+    for node in graph.node_identifiers() {
+        let a = graph.to_index(node);
+        present[a] = true;
+        for next in graph.neighbors(node) {
+            sets.union(a, graph.to_index(next));
+        }
+    }
+
+    sets.into_sets()
+        .into_iter()
+        .filter(|set| present[set[0]])
+        .map(|set| set.into_iter().map(|i| graph.to_node(i)).collect())
+        .collect()
+}
+
+pub fn strongly_connected_components<G>(graph: G) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + Visitable + GraphProp,
+{
+    tarjan_scc(graph)
+}
+
+pub fn tarjan_scc<G>(graph: G) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + Visitable + GraphProp,
+{
+    if !graph.is_directed() {
+        return connected_components(graph);
+    }
+
+    let size = graph.node_bound();
+    let mut order = vec![0; size];
+    let mut low = vec![0; size];
+    let mut parents = vec![None; size];
+    let mut on_stack = vec![false; size];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut counter = 0;
+
+    let mut events = match graph.node_identifiers().next() {
+        Some(node) => DepthFirstEvents::new(graph, node),
+        None => return components,
+    };
+
+    for start in graph.node_identifiers() {
+        events.move_to(start);
+
+        for event in events.by_ref() {
+            match event {
+                DfsEvent::Discover(v) => {
+                    let v = graph.to_index(v);
+                    order[v] = counter;
+                    low[v] = counter;
+                    counter += 1;
+                    stack.push(v);
+                    on_stack[v] = true;
+                }
+                DfsEvent::TreeEdge(u, v) => parents[graph.to_index(v)] = Some(graph.to_index(u)),
+                DfsEvent::BackEdge(u, v)
+                | DfsEvent::ForwardEdge(u, v)
+                | DfsEvent::CrossEdge(u, v) => {
+                    let (u, v) = (graph.to_index(u), graph.to_index(v));
+                    if on_stack[v] {
+                        low[u] = low[u].min(order[v]);
+                    }
+                }
+                DfsEvent::Finish(v) => {
+                    let v = graph.to_index(v);
+                    if low[v] == order[v] {
+                        let mut component = Vec::new();
+                        while let Some(w) = stack.pop() {
+                            on_stack[w] = false;
+                            component.push(w);
+                            if w == v {
+                                break;
+                            }
+                        }
+                        component.sort_unstable();
+                        components.push(component.into_iter().map(|w| graph.to_node(w)).collect());
+                    }
+
+                    if let Some(u) = parents[v] {
+                        low[u] = low[u].min(low[v]);
+                    }
+                }
+            }
+        }
+    }
+
+    components
+}
+
+pub fn kosaraju_scc<G>(graph: G) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + Visitable + GraphProp,
+{
+    let mut finished = Vec::new();
+    let mut components = Vec::new();
+
+    let mut events = match graph.node_identifiers().next() {
+        Some(node) => DepthFirstEvents::new(graph, node),
+        None => return components,
+    };
+
+    for start in graph.node_identifiers() {
+        events.move_to(start);
+        finished.extend(events.by_ref().filter_map(|event| match event {
+            DfsEvent::Finish(v) => Some(graph.to_index(v)),
+            _ => None,
+        }));
+    }
+
+    let mut incoming = vec![Vec::new(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let a = graph.to_index(node);
+        for next in graph.neighbors(node) {
+            incoming[graph.to_index(next)].push(a);
+        }
+    }
+
+    let mut assigned = vec![false; graph.node_bound()];
+    for &root in finished.iter().rev() {
+        if assigned[root] {
+            continue;
+        }
+
+        assigned[root] = true;
+        let mut component = Vec::new();
+        let mut stack = vec![root];
+        while let Some(v) = stack.pop() {
+            component.push(v);
+            for &u in &incoming[v] {
+                if !assigned[u] {
+                    assigned[u] = true;
+                    stack.push(u);
+                }
+            }
+        }
+
+        component.sort_unstable();
+        components.push(component.into_iter().map(|v| graph.to_node(v)).collect());
+    }
+
+    components
+}
+
+pub fn condensation<G>(graph: G) -> Graph<Vec<G::NodeId>, (), AdjacencyList<()>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + Visitable + GraphProp,
+{
+    let mut components = tarjan_scc(graph);
+    components.reverse();
+
+    let mut labels = vec![0; graph.node_bound()];
+    for (label, component) in components.iter().enumerate() {
+        component
+            .iter()
+            .for_each(|&v| labels[graph.to_index(v)] = label);
+    }
+
+    let mut dag = Graph::new_directed();
+    components.into_iter().for_each(|c| dag.add_node(c));
+    for node in graph.node_identifiers() {
+        let a = labels[graph.to_index(node)];
+        for next in graph.neighbors(node) {
+            let b = labels[graph.to_index(next)];
+            if a != b {
+                dag.set_edge(a, b, ());
+            }
+        }
+    }
+
+    dag
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directed_test_graph() -> Graph<(), ()> {
+        let mut g = Graph::<(), ()>::new_directed();
+        for _ in 0..8 {
+            g.add_node(());
+        }
+        for (a, b) in [
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 3),
+            (4, 5),
+            (6, 5),
+            (5, 7),
+            (7, 6),
+        ] {
+            g.set_edge(a, b, ());
+        }
+
+        g
+    }
+
+    fn sorted(mut components: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+        components.sort();
+        components
+    }
+
+    #[test]
+    fn can_find_scc_with_tarjan() {
+        let g = directed_test_graph();
+
+        assert_eq!(
+            tarjan_scc(&g),
+            vec![vec![5, 6, 7], vec![3, 4], vec![0, 1, 2]]
+        );
+    }
+
+    #[test]
+    fn can_find_scc_with_kosaraju() {
+        let g = directed_test_graph();
+
+        assert_eq!(
+            sorted(kosaraju_scc(&g)),
+            vec![vec![0, 1, 2], vec![3, 4], vec![5, 6, 7]]
+        );
+        assert_eq!(kosaraju_scc(&g)[0], vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn can_find_components_of_undirected_graph() {
+        let mut g = Graph::<(), ()>::new_undirected();
+        for _ in 0..5 {
+            g.add_node(());
+        }
+        g.set_edge(0, 3, ());
+        g.set_edge(3, 1, ());
+        g.set_edge(2, 4, ());
+
+        let expected = vec![vec![0, 1, 3], vec![2, 4]];
+        assert_eq!(connected_components(&g), expected);
+        assert_eq!(sorted(tarjan_scc(&g)), expected);
+        assert_eq!(sorted(kosaraju_scc(&g)), expected);
+    }
+
+    #[test]
+    fn can_build_condensation() {
+        let g = directed_test_graph();
+        let dag = condensation(&g);
+
+        assert!(dag.is_directed());
+        assert_eq!(dag.node_count(), 3);
+        assert_eq!(dag.node(0).unwrap().value(), &vec![0, 1, 2]);
+        assert_eq!(dag.node(1).unwrap().value(), &vec![3, 4]);
+        assert_eq!(dag.node(2).unwrap().value(), &vec![5, 6, 7]);
+        assert_eq!(dag.edge_count(), 2);
+        assert!(dag.edge(0, 1).is_some());
+        assert!(dag.edge(1, 2).is_some());
+    }
+}
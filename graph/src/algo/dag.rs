@@ -0,0 +1,228 @@
+use crate::graph::Graph;
+use crate::list::AdjacencyList;
+use crate::matrix::AdjacencyMatrix;
+use crate::visit::{
+    GraphBase, IntoEdges, IntoNeighbors, IntoNodeIdentifiers, NodeCount, NodeIndexable,
+};
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cycle<K = usize> {
+    nodes: Vec<K>,
+}
+
+impl<K> Cycle<K> {
+    pub fn nodes(&self) -> &[K] {
+        &self.nodes
+    }
+}
+
+pub fn toposort<G>(graph: G) -> Result<Vec<G::NodeId>, Cycle<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + NodeCount,
+{
+    let size = graph.node_bound();
+    let mut degrees = vec![0; size];
+    for v in graph.node_identifiers() {
+        graph
+            .neighbors(v)
+            .for_each(|next| degrees[graph.to_index(next)] += 1);
+    }
+
+    let mut queue: VecDeque<G::NodeId> = graph
+        .node_identifiers()
+        .filter(|&v| degrees[graph.to_index(v)] == 0)
+        .collect();
+    let mut order = Vec::with_capacity(graph.node_count());
+
+    while let Some(v) = queue.pop_front() {
+        order.push(v);
+        for next in graph.neighbors(v) {
+            let degree = &mut degrees[graph.to_index(next)];
+            *degree -= 1;
+            if *degree == 0 {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    if order.len() == graph.node_count() {
+        return Ok(order);
+    }
+
+    let remaining = |v: G::NodeId| degrees[graph.to_index(v)] > 0;
+    let mut previous = vec![None; size];
+    for v in graph.node_identifiers().filter(|&v| remaining(v)) {
+        for next in graph.neighbors(v).filter(|&next| remaining(next)) {
+            previous[graph.to_index(next)].get_or_insert(v);
+        }
+    }
+
+    let mut visited = vec![false; size];
+    let mut current = graph.node_identifiers().find(|&v| remaining(v)).unwrap();
+    let mut walk = Vec::new();
+    while !visited[graph.to_index(current)] {
+        visited[graph.to_index(current)] = true;
+        walk.push(current);
+        current = previous[graph.to_index(current)].unwrap();
+    }
+
+    let start = walk.iter().position(|&v| v == current).unwrap();
+    let mut nodes = walk.split_off(start);
+    nodes.reverse();
+
+    Err(Cycle { nodes })
+}
+
+pub fn transitive_closure<G>(graph: G) -> AdjacencyMatrix<bool>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let size = graph.node_bound();
+    let mut closure = AdjacencyMatrix::<bool>::new();
+    (0..size).for_each(|_| closure.push_default());
+
+    let mut queue = VecDeque::new();
+    for start in graph.node_identifiers() {
+        let row = graph.to_index(start);
+        queue.push_back(start);
+        while let Some(v) = queue.pop_front() {
+            for next in graph.neighbors(v) {
+                if !*closure.get(row, graph.to_index(next)) {
+                    closure.set(row, graph.to_index(next), true);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    closure
+}
+
+pub type Reduction<G> = Graph<
+    <G as GraphBase>::NodeId,
+    <G as IntoEdges>::EdgeWeight,
+    AdjacencyList<<G as IntoEdges>::EdgeWeight>,
+>;
+
+pub fn transitive_reduction<G>(graph: G) -> Result<Reduction<G>, Cycle<G::NodeId>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount,
+{
+    toposort(graph)?;
+
+    let size = graph.node_bound();
+    let closure = transitive_closure(graph);
+    let mut redundant = AdjacencyMatrix::<bool>::new();
+    (0..size).for_each(|_| redundant.push_default());
+
+    for u in graph.node_identifiers() {
+        for w in graph.neighbors(u) {
+            (0..size)
+                .filter(|&v| *closure.get(graph.to_index(w), v))
+                .for_each(|v| redundant.set(graph.to_index(u), v, true));
+        }
+    }
+
+    // nodes keep the ids of the input graph, in the order they are listed
+    let mut reduction = Graph::new_directed();
+    let mut labels = vec![0; size];
+    for (label, node) in graph.node_identifiers().enumerate() {
+        labels[graph.to_index(node)] = label;
+        reduction.add_node(node);
+    }
+    for u in graph.node_identifiers() {
+        let a = graph.to_index(u);
+        for (v, e) in graph.edges(u) {
+            let b = graph.to_index(v);
+            if !*redundant.get(a, b) {
+                reduction.set_edge(labels[a], labels[b], e);
+            }
+        }
+    }
+
+    Ok(reduction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dag_test_graph() -> Graph<&'static str, ()> {
+        let mut g = Graph::<&str, ()>::new_directed();
+        for name in ["shirt", "tie", "jacket", "belt", "trousers", "shoes"] {
+            g.add_node(name);
+        }
+        for (a, b) in [
+            (0, 1),
+            (1, 2),
+            (0, 3),
+            (3, 2),
+            (4, 3),
+            (4, 5),
+            (0, 2),
+            (4, 2),
+        ] {
+            g.set_edge(a, b, ());
+        }
+
+        g
+    }
+
+    #[test]
+    fn can_sort_topologically() {
+        let g = dag_test_graph();
+        let order = toposort(&g).unwrap();
+
+        assert_eq!(order.len(), 6);
+        for e in g.edges() {
+            let a = order.iter().position(|&v| v == e.index_a()).unwrap();
+            let b = order.iter().position(|&v| v == e.index_b()).unwrap();
+            assert!(a < b);
+        }
+    }
+
+    #[test]
+    fn can_report_cycle() {
+        let mut g = dag_test_graph();
+        g.set_edge(2, 4, ());
+
+        let cycle = toposort(&g).unwrap_err();
+        let nodes = cycle.nodes();
+        assert!(nodes.len() >= 2);
+        for i in 0..nodes.len() {
+            assert!(g.edge(nodes[i], nodes[(i + 1) % nodes.len()]).is_some());
+        }
+    }
+
+    #[test]
+    fn can_build_transitive_closure() {
+        let g = dag_test_graph();
+        let closure = transitive_closure(&g);
+
+        assert!(*closure.get(0, 2));
+        assert!(*closure.get(4, 2));
+        assert!(*closure.get(1, 2));
+        assert!(!*closure.get(2, 0));
+        assert!(!*closure.get(0, 0));
+        assert!(!*closure.get(0, 5));
+    }
+
+    #[test]
+    fn can_build_transitive_reduction() {
+        let g = dag_test_graph();
+        let reduction = transitive_reduction(&g).unwrap();
+
+        assert_eq!(reduction.node_count(), 6);
+        assert_eq!(reduction.edge_count(), 6);
+        assert_eq!(reduction.node(5).unwrap().value(), &5);
+        assert!(reduction.edge(0, 2).is_none());
+        assert!(reduction.edge(4, 2).is_none());
+        assert!(reduction.edge(0, 1).is_some());
+        assert_eq!(transitive_closure(&reduction), transitive_closure(&g));
+
+        let mut g = g;
+        g.set_edge(2, 0, ());
+        assert!(transitive_reduction(&g).is_err());
+    }
+}
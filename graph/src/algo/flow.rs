@@ -0,0 +1,271 @@
+use crate::algo::{edge_list, Measure};
+use crate::error::GraphError;
+use crate::visit::{GraphProp, IntoEdges, IntoNodeIdentifiers, NodeIndexable};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Sub;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxFlow<W, K = usize>
+where
+    K: Eq + Hash,
+{
+    value: W,
+    flows: Vec<(K, K, W)>,
+    arcs: HashMap<(K, K), usize>,
+    source_side: Vec<K>,
+    sink_side: Vec<K>,
+    reachable: HashSet<K>,
+}
+
+impl<W, K> MaxFlow<W, K>
+where
+    W: Measure,
+    K: Copy + Eq + Hash,
+{
+    pub fn value(&self) -> W {
+        self.value
+    }
+
+    pub fn flows(&self) -> &[(K, K, W)] {
+        &self.flows
+    }
+
+    pub fn flow(&self, a: K, b: K) -> Option<W> {
+        self.arcs.get(&(a, b)).map(|&i| self.flows[i].2)
+    }
+
+    pub fn is_source_side(&self, node: K) -> bool {
+        self.reachable.contains(&node)
+    }
+
+    pub fn source_side(&self) -> &[K] {
+        &self.source_side
+    }
+
+    pub fn sink_side(&self) -> &[K] {
+        &self.sink_side
+    }
+}
+
+pub fn edmonds_karp<G, W, F>(
+    graph: G,
+    source: G::NodeId,
+    sink: G::NodeId,
+    mut capacity: F,
+) -> Result<MaxFlow<W, G::NodeId>, GraphError>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    W: Measure + Sub<Output = W>,
+    F: FnMut(G::EdgeWeight) -> W,
+{
+    let size = graph.node_bound();
+    for index in [graph.to_index(source), graph.to_index(sink)] {
+        if index >= size {
+            return Err(GraphError::NodeOutOfBounds {
+                index,
+                node_count: size,
+            });
+        }
+    }
+    if source == sink {
+        return Err(GraphError::SourceIsSink {
+            index: graph.to_index(source),
+        });
+    }
+
+    let (source, sink) = (graph.to_index(source), graph.to_index(sink));
+
+    // arcs come in pairs, arc `i ^ 1` is the reverse of arc `i`
+    let mut targets = Vec::new();
+    let mut capacities = Vec::new();
+    let mut residual = Vec::new();
+    let mut arcs: Vec<Vec<usize>> = vec![Vec::new(); size];
+
+    for (a, b, e) in edge_list(graph) {
+        let (a, b) = (graph.to_index(a), graph.to_index(b));
+        let c = capacity(e);
+        let reverse = match graph.is_directed() {
+            true => W::default(),
+            false => c,
+        };
+
+        arcs[a].push(targets.len());
+        targets.push(b);
+        capacities.push(c);
+        residual.push(c);
+
+        arcs[b].push(targets.len());
+        targets.push(a);
+        capacities.push(reverse);
+        residual.push(reverse);
+    }
+
+    let zero = W::default();
+    let mut value = zero;
+    let mut predecessors: Vec<Option<usize>> = vec![None; size];
+
+    loop {
+        predecessors.iter_mut().for_each(|p| *p = None);
+        let mut visited = vec![false; size];
+        let mut queue = VecDeque::from([source]);
+        visited[source] = true;
+
+        while let Some(v) = queue.pop_front() {
+            for &arc in &arcs[v] {
+                let next = targets[arc];
+                if !visited[next] && residual[arc] > zero {
+                    visited[next] = true;
+                    predecessors[next] = Some(arc);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        if !visited[sink] {
+            let flows: Vec<(G::NodeId, G::NodeId, W)> = (0..targets.len())
+                .step_by(2)
+                .filter_map(|arc| {
+                    let (a, b) = (graph.to_node(targets[arc + 1]), graph.to_node(targets[arc]));
+                    if residual[arc] < capacities[arc] {
+                        Some((a, b, capacities[arc] - residual[arc]))
+                    } else if residual[arc + 1] < capacities[arc + 1] {
+                        Some((b, a, capacities[arc + 1] - residual[arc + 1]))
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            let arcs = flows
+                .iter()
+                .enumerate()
+                .map(|(i, &(a, b, _))| ((a, b), i))
+                .collect();
+
+            let (source_side, sink_side): (Vec<G::NodeId>, Vec<G::NodeId>) = graph
+                .node_identifiers()
+                .partition(|&v| visited[graph.to_index(v)]);
+
+            return Ok(MaxFlow {
+                value,
+                flows,
+                arcs,
+                reachable: source_side.iter().copied().collect(),
+                source_side,
+                sink_side,
+            });
+        }
+
+        let mut bottleneck = None;
+        let mut v = sink;
+        while let Some(arc) = predecessors[v] {
+            bottleneck = match bottleneck {
+                Some(b) if b < residual[arc] => Some(b),
+                _ => Some(residual[arc]),
+            };
+            v = targets[arc ^ 1];
+        }
+
+        let bottleneck = bottleneck.unwrap();
+        let mut v = sink;
+        while let Some(arc) = predecessors[v] {
+            residual[arc] = residual[arc] - bottleneck;
+            residual[arc ^ 1] = residual[arc ^ 1] + bottleneck;
+            v = targets[arc ^ 1];
+        }
+        value = value + bottleneck;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Graph;
+
+    fn directed_test_graph() -> Graph<(), u32> {
+        let mut g = Graph::<(), u32>::new_directed();
+        for _ in 0..6 {
+            g.add_node(());
+        }
+        for (a, b, c) in [
+            (0, 1, 16),
+            (0, 2, 13),
+            (2, 1, 4),
+            (1, 3, 12),
+            (3, 2, 9),
+            (2, 4, 14),
+            (4, 3, 7),
+            (3, 5, 20),
+            (4, 5, 4),
+        ] {
+            g.set_edge(a, b, c);
+        }
+
+        g
+    }
+
+    #[test]
+    fn can_find_max_flow() {
+        let g = directed_test_graph();
+        let flow = edmonds_karp(&g, 0, 5, |c| *c).unwrap();
+
+        assert_eq!(flow.value(), 23);
+        for &(a, b, f) in flow.flows() {
+            assert!(f <= *g.edge(a, b).unwrap().value());
+        }
+
+        let outgoing: u32 = flow.flows().iter().filter(|e| e.0 == 0).map(|e| e.2).sum();
+        assert_eq!(outgoing, 23);
+    }
+
+    #[test]
+    fn can_find_min_cut() {
+        let g = directed_test_graph();
+        let flow = edmonds_karp(&g, 0, 5, |c| *c).unwrap();
+
+        assert_eq!(flow.source_side(), vec![0, 1, 2, 4]);
+        assert_eq!(flow.sink_side(), vec![3, 5]);
+        let cut: u32 = g
+            .edges()
+            .filter(|e| flow.is_source_side(e.index_a()) && !flow.is_source_side(e.index_b()))
+            .map(|e| *e.value())
+            .sum();
+        assert_eq!(cut, 23);
+    }
+
+    #[test]
+    fn can_find_undirected_max_flow() {
+        let mut g = Graph::<(), u32>::new_undirected();
+        for _ in 0..4 {
+            g.add_node(());
+        }
+        g.set_edge(0, 1, 3);
+        g.set_edge(0, 2, 2);
+        g.set_edge(1, 2, 5);
+        g.set_edge(1, 3, 2);
+        g.set_edge(2, 3, 3);
+
+        let flow = edmonds_karp(&g, 3, 0, |c| *c).unwrap();
+        assert_eq!(flow.value(), 5);
+        assert_eq!(flow.flow(1, 0), Some(3));
+        assert_eq!(flow.flow(2, 0), Some(2));
+        assert_eq!(flow.source_side(), vec![3]);
+    }
+
+    #[test]
+    fn can_reject_invalid_terminals() {
+        let g = directed_test_graph();
+        let out_of_bounds = GraphError::NodeOutOfBounds {
+            index: 6,
+            node_count: 6,
+        };
+
+        assert_eq!(edmonds_karp(&g, 6, 5, |c| *c), Err(out_of_bounds));
+        assert_eq!(edmonds_karp(&g, 0, 6, |c| *c), Err(out_of_bounds));
+        assert_eq!(
+            edmonds_karp(&g, 2, 2, |c| *c),
+            Err(GraphError::SourceIsSink { index: 2 })
+        );
+    }
+}
@@ -0,0 +1,380 @@
+use crate::algo::{edge_list, Measure};
+use crate::predicates::bipartition;
+use crate::visit::{
+    GraphBase, GraphProp, IntoEdges, IntoNodeIdentifiers, NodeIndexable, NodeMap, Visitable,
+};
+use std::collections::VecDeque;
+use std::ops::Sub;
+
+pub type Matching<G> = Vec<(<G as GraphBase>::NodeId, <G as GraphBase>::NodeId)>;
+
+struct Bipartite<W> {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    // edges as (left label, right label, weight)
+    edges: Vec<(usize, usize, W)>,
+}
+
+fn split<G>(graph: G, partition: Option<&[bool]>) -> Option<Bipartite<G::EdgeWeight>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable + GraphProp,
+{
+    let sides = match partition {
+        Some(sides) => sides.to_vec(),
+        None => {
+            let colors = bipartition(graph)?;
+            let mut sides = vec![false; graph.node_bound()];
+            for node in graph.node_identifiers() {
+                sides[graph.to_index(node)] = *colors.get(node)?;
+            }
+            sides
+        }
+    };
+
+    if sides.len() != graph.node_bound() {
+        return None;
+    }
+
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|node| graph.to_index(node))
+        .collect();
+    let left: Vec<usize> = nodes.iter().copied().filter(|&v| !sides[v]).collect();
+    let right: Vec<usize> = nodes.iter().copied().filter(|&v| sides[v]).collect();
+    let mut labels = vec![0; sides.len()];
+    left.iter().enumerate().for_each(|(i, &v)| labels[v] = i);
+    right.iter().enumerate().for_each(|(i, &v)| labels[v] = i);
+
+    let mut edges = Vec::new();
+    for (a, b, e) in edge_list(graph) {
+        let (a, b) = (graph.to_index(a), graph.to_index(b));
+        match (sides[a], sides[b]) {
+            (false, true) => edges.push((labels[a], labels[b], e)),
+            (true, false) => edges.push((labels[b], labels[a], e)),
+            _ => return None,
+        }
+    }
+
+    Some(Bipartite { left, right, edges })
+}
+
+pub fn hopcroft_karp<G>(graph: G, partition: Option<&[bool]>) -> Option<Matching<G>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable + GraphProp,
+{
+    const INF: usize = usize::MAX;
+
+    let Bipartite { left, right, edges } = split(graph, partition)?;
+    let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); left.len()];
+    edges.iter().for_each(|&(u, v, _)| adjacency[u].push(v));
+
+    let mut pair_left: Vec<Option<usize>> = vec![None; left.len()];
+    let mut pair_right: Vec<Option<usize>> = vec![None; right.len()];
+    let mut distances = vec![INF; left.len()];
+
+    loop {
+        let mut queue = VecDeque::new();
+        for u in 0..left.len() {
+            distances[u] = match pair_left[u] {
+                None => {
+                    queue.push_back(u);
+                    0
+                }
+                Some(_) => INF,
+            };
+        }
+
+        let mut found = INF;
+        while let Some(u) = queue.pop_front() {
+            if distances[u] >= found {
+                continue;
+            }
+
+            for &v in &adjacency[u] {
+                match pair_right[v] {
+                    None if found == INF => found = distances[u] + 1,
+                    Some(w) if distances[w] == INF => {
+                        distances[w] = distances[u] + 1;
+                        queue.push_back(w);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if found == INF {
+            break;
+        }
+
+        for root in 0..left.len() {
+            if pair_left[root].is_some() {
+                continue;
+            }
+
+            let mut stack = vec![(root, 0)];
+            let mut via = Vec::new();
+            while let Some((u, next)) = stack.last_mut() {
+                let u = *u;
+                if *next >= adjacency[u].len() {
+                    distances[u] = INF;
+                    stack.pop();
+                    via.pop();
+                    continue;
+                }
+
+                let v = adjacency[u][*next];
+                *next += 1;
+                match pair_right[v] {
+                    None if distances[u] + 1 == found => {
+                        via.push(v);
+                        for (&(u, _), &v) in stack.iter().zip(via.iter()) {
+                            pair_left[u] = Some(v);
+                            pair_right[v] = Some(u);
+                        }
+                        break;
+                    }
+                    Some(w) if distances[w] == distances[u] + 1 => {
+                        via.push(v);
+                        stack.push((w, 0));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let matching = pair_left
+        .iter()
+        .enumerate()
+        .filter_map(|(u, v)| v.map(|v| (graph.to_node(left[u]), graph.to_node(right[v]))))
+        .collect();
+
+    Some(matching)
+}
+
+pub fn hungarian<G, W, F>(
+    graph: G,
+    partition: Option<&[bool]>,
+    mut weight: F,
+) -> Option<(W, Matching<G>)>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable + GraphProp,
+    W: Measure + Sub<Output = W>,
+    F: FnMut(G::EdgeWeight) -> W,
+{
+    let Bipartite { left, right, edges } = split(graph, partition)?;
+
+    // the larger side goes to the columns, so every row can be assigned
+    let transposed = left.len() > right.len();
+    let (left, right) = match transposed {
+        true => (right, left),
+        false => (left, right),
+    };
+    let (n, m) = (left.len(), right.len());
+
+    // rows and columns are indexed from 1, column 0 is a sentinel
+    let mut costs: Vec<Vec<Option<W>>> = vec![vec![None; m + 1]; n + 1];
+    for (u, v, e) in edges {
+        let (row, column) = match transposed {
+            true => (v, u),
+            false => (u, v),
+        };
+        costs[row + 1][column + 1] = Some(weight(e));
+    }
+
+    // column potentials are stored negated, so unsigned weights never underflow
+    let zero = W::default();
+    let mut u = vec![zero; n + 1];
+    let mut v = vec![zero; m + 1];
+    let mut rows = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+
+    for i in 1..=n {
+        rows[0] = i;
+        let mut j0 = 0;
+        let mut minimums: Vec<Option<W>> = vec![None; m + 1];
+        let mut used = vec![false; m + 1];
+
+        loop {
+            used[j0] = true;
+            let i0 = rows[j0];
+            let mut delta = None;
+            let mut j1 = 0;
+
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+
+                if let Some(cost) = costs[i0][j] {
+                    let current = cost + v[j] - u[i0];
+                    if minimums[j].is_none_or(|min| current < min) {
+                        minimums[j] = Some(current);
+                        way[j] = j0;
+                    }
+                }
+
+                if let Some(min) = minimums[j] {
+                    if delta.is_none_or(|d| min < d) {
+                        delta = Some(min);
+                        j1 = j;
+                    }
+                }
+            }
+
+            let delta = delta?;
+            for j in 0..=m {
+                if used[j] {
+                    u[rows[j]] = u[rows[j]] + delta;
+                    v[j] = v[j] + delta;
+                } else if let Some(min) = minimums[j].as_mut() {
+                    *min = *min - delta;
+                }
+            }
+
+            j0 = j1;
+            if rows[j0] == 0 {
+                break;
+            }
+        }
+
+        while j0 != 0 {
+            let j1 = way[j0];
+            rows[j0] = rows[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut total = zero;
+    let mut matching = Vec::with_capacity(n);
+    for j in 1..=m {
+        if rows[j] != 0 {
+            total = total + costs[rows[j]][j].unwrap();
+            let (a, b) = (left[rows[j] - 1], right[j - 1]);
+            matching.push(if transposed { (b, a) } else { (a, b) });
+        }
+    }
+    matching.sort_unstable();
+    let matching = matching
+        .into_iter()
+        .map(|(a, b)| (graph.to_node(a), graph.to_node(b)))
+        .collect();
+
+    Some((total, matching))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Graph;
+
+    // workers 0..3 on the left, jobs 3..6 on the right
+    fn assignment_test_graph() -> Graph<(), i32> {
+        let mut g = Graph::<(), i32>::new_undirected();
+        for _ in 0..6 {
+            g.add_node(());
+        }
+        for (a, b, c) in [
+            (0, 3, 4),
+            (0, 4, 1),
+            (0, 5, 3),
+            (1, 3, 2),
+            (1, 4, 0),
+            (1, 5, 5),
+            (2, 3, 3),
+            (2, 4, 2),
+            (2, 5, 2),
+        ] {
+            g.set_edge(a, b, c);
+        }
+
+        g
+    }
+
+    fn sides() -> Vec<bool> {
+        vec![false, false, false, true, true, true]
+    }
+
+    #[test]
+    fn can_find_maximum_matching() {
+        let mut g = Graph::<(), ()>::new_undirected();
+        for _ in 0..8 {
+            g.add_node(());
+        }
+        for (a, b) in [(0, 4), (0, 5), (1, 4), (2, 5), (2, 6), (3, 6)] {
+            g.set_edge(a, b, ());
+        }
+
+        let matching = hopcroft_karp(&g, None).unwrap();
+        assert_eq!(matching.len(), 3);
+        for &(a, b) in &matching {
+            assert!(g.edge(a, b).is_some());
+        }
+
+        let mut matched: Vec<usize> = matching.iter().flat_map(|&(a, b)| [a, b]).collect();
+        matched.sort_unstable();
+        matched.dedup();
+        assert_eq!(matched.len(), 6);
+    }
+
+    #[test]
+    fn can_find_perfect_matching() {
+        let g = assignment_test_graph();
+        let matching = hopcroft_karp(&g, Some(&sides())).unwrap();
+
+        assert_eq!(matching.len(), 3);
+        assert!(matching.iter().all(|&(a, b)| a < 3 && b >= 3));
+    }
+
+    #[test]
+    fn can_reject_invalid_partition() {
+        let mut g = assignment_test_graph();
+        assert_eq!(hopcroft_karp(&g, Some(&[false; 6])), None);
+
+        g.set_edge(0, 1, 0);
+        assert_eq!(hopcroft_karp(&g, None), None);
+    }
+
+    #[test]
+    fn can_find_min_cost_assignment() {
+        let g = assignment_test_graph();
+        let (total, matching) = hungarian(&g, Some(&sides()), |c| *c).unwrap();
+
+        assert_eq!(total, 5);
+        assert_eq!(matching, vec![(0, 4), (1, 3), (2, 5)]);
+
+        let (total, _) = hungarian(&g, Some(&sides()), |c| -*c).unwrap();
+        assert_eq!(total, -11);
+
+        let (total, matching) = hungarian(&g, Some(&sides()), |c| *c as u32).unwrap();
+        assert_eq!(total, 5);
+        assert_eq!(matching, vec![(0, 4), (1, 3), (2, 5)]);
+    }
+
+    #[test]
+    fn can_assign_smaller_side() {
+        let mut g = assignment_test_graph();
+        g.remove_node(5);
+        g.set_edge(2, 3, 5);
+        let sides = [false, false, false, true, true];
+
+        let (total, matching) = hungarian(&g, Some(&sides), |c| *c).unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(matching, vec![(0, 4), (1, 3)]);
+
+        let flipped = sides.map(|side| !side);
+        let (total, matching) = hungarian(&g, Some(&flipped), |c| *c).unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(matching, vec![(3, 1), (4, 0)]);
+    }
+
+    #[test]
+    fn can_detect_infeasible_assignment() {
+        let mut g = assignment_test_graph();
+        g.remove_edge(0, 3);
+        g.remove_edge(1, 3);
+        g.remove_edge(2, 3);
+
+        assert_eq!(hungarian(&g, Some(&sides()), |c| *c), None);
+    }
+}
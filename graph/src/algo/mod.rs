@@ -0,0 +1,32 @@
+use crate::visit::{GraphBase, GraphProp, IntoEdges, IntoNodeIdentifiers, NodeIndexable};
+use std::ops::Add;
+
+pub mod biconnected;
+pub mod components;
+pub mod dag;
+pub mod flow;
+pub mod matching;
+pub mod mst;
+pub mod shortest_path;
+
+pub trait Measure: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T> Measure for T where T: Copy + Ord + Add<Output = Self> + Default {}
+
+pub type Edge<G> = (
+    <G as GraphBase>::NodeId,
+    <G as GraphBase>::NodeId,
+    <G as IntoEdges>::EdgeWeight,
+);
+
+pub(crate) fn edge_list<G>(graph: G) -> Vec<Edge<G>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    graph
+        .node_identifiers()
+        .flat_map(|a| graph.edges(a).map(move |(b, e)| (a, b, e)))
+        // an undirected edge is listed from both of its ends
+        .filter(|&(a, b, _)| graph.is_directed() || graph.to_index(a) >= graph.to_index(b))
+        .collect()
+}
@@ -0,0 +1,224 @@
+use crate::algo::{edge_list, Edge};
+use crate::graph::Graph;
+use crate::storage::GraphStorage;
+use crate::unionfind::DisjointSet;
+use crate::visit::{GraphProp, IntoEdges, IntoNodeIdentifiers, NodeIndexable};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub fn kruskal<G, W, F>(graph: G, mut weight: F) -> Vec<Edge<G>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::EdgeWeight: Copy,
+    W: Ord,
+    F: FnMut(G::EdgeWeight) -> W,
+{
+    let mut edges: Vec<(W, Edge<G>)> = edge_list(graph)
+        .into_iter()
+        .map(|e| (weight(e.2), e))
+        .collect();
+    edges.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut sets = DisjointSet::new(graph.node_bound());
+    edges
+        .into_iter()
+        .map(|(_, e)| e)
+        .filter(|&(a, b, _)| sets.union(graph.to_index(a), graph.to_index(b)))
+        .collect()
+}
+
+pub fn prim<G, W, F>(graph: G, mut weight: F) -> Vec<Edge<G>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::EdgeWeight: Copy,
+    W: Ord,
+    F: FnMut(G::EdgeWeight) -> W,
+{
+    let edges = edge_list(graph);
+    let ends: Vec<(usize, usize)> = edges
+        .iter()
+        .map(|&(a, b, _)| (graph.to_index(a), graph.to_index(b)))
+        .collect();
+    let mut incident = vec![Vec::new(); graph.node_bound()];
+    for (i, &(a, b)) in ends.iter().enumerate() {
+        incident[a].push(i);
+        if a != b {
+            incident[b].push(i);
+        }
+    }
+
+    let mut visited = vec![false; graph.node_bound()];
+    let mut queue = BinaryHeap::new();
+    let mut tree = Vec::new();
+
+    for start in graph.node_identifiers().map(|node| graph.to_index(node)) {
+        if visited[start] {
+            continue;
+        }
+
+        let mut next = Some(start);
+        while let Some(index) = next.take() {
+            visited[index] = true;
+            for &i in &incident[index] {
+                let (a, b) = ends[i];
+                if !visited[a] || !visited[b] {
+                    queue.push(Reverse((weight(edges[i].2), i)));
+                }
+            }
+
+            while let Some(Reverse((_, i))) = queue.pop() {
+                let (a, b) = ends[i];
+                match (visited[a], visited[b]) {
+                    (true, false) => next = Some(b),
+                    (false, true) => next = Some(a),
+                    _ => continue,
+                }
+                tree.push(edges[i]);
+                break;
+            }
+        }
+    }
+
+    tree
+}
+
+pub fn boruvka<G, W, F>(graph: G, mut weight: F) -> Vec<Edge<G>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::EdgeWeight: Copy,
+    W: Ord,
+    F: FnMut(G::EdgeWeight) -> W,
+{
+    let edges: Vec<(W, usize, usize, Edge<G>)> = edge_list(graph)
+        .into_iter()
+        .map(|e| (weight(e.2), graph.to_index(e.0), graph.to_index(e.1), e))
+        .collect();
+    let mut sets = DisjointSet::new(graph.node_bound());
+    let mut tree = Vec::new();
+
+    loop {
+        let mut cheapest: Vec<Option<usize>> = vec![None; graph.node_bound()];
+        for (i, (w, a, b, _)) in edges.iter().enumerate() {
+            let root_a = sets.find(*a);
+            let root_b = sets.find(*b);
+            if root_a == root_b {
+                continue;
+            }
+
+            for root in [root_a, root_b] {
+                match cheapest[root] {
+                    Some(j) if edges[j].0 <= *w => {}
+                    _ => cheapest[root] = Some(i),
+                }
+            }
+        }
+
+        let mut merged = false;
+        for i in cheapest.into_iter().flatten() {
+            let (_, a, b, e) = edges[i];
+            if sets.union(a, b) {
+                tree.push(e);
+                merged = true;
+            }
+        }
+
+        if !merged {
+            break;
+        }
+    }
+
+    tree
+}
+
+pub fn to_graph<N, E, S>(graph: &Graph<N, E, S>, edges: &[(usize, usize, &E)]) -> Graph<N, E, S>
+where
+    N: Clone,
+    E: Clone,
+    S: GraphStorage<E>,
+{
+    let mut tree = match graph.is_directed() {
+        true => Graph::new_directed(),
+        false => Graph::new_undirected(),
+    };
+
+    graph.nodes().for_each(|n| tree.add_node(n.value().clone()));
+    edges
+        .iter()
+        .for_each(|&(a, b, e)| tree.set_edge(a, b, e.clone()));
+
+    tree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weighted_test_graph() -> Graph<&'static str, u32> {
+        let mut g = Graph::<&str, u32>::new_undirected();
+        for name in ["A", "B", "C", "D", "E", "F"] {
+            g.add_node(name);
+        }
+
+        g.set_edge(0, 1, 4);
+        g.set_edge(0, 2, 1);
+        g.set_edge(1, 2, 2);
+        g.set_edge(1, 3, 5);
+        g.set_edge(2, 3, 8);
+        g.set_edge(3, 4, 3);
+        g.set_edge(2, 4, 9);
+
+        g
+    }
+
+    fn total(edges: &[(usize, usize, &u32)]) -> u32 {
+        edges.iter().map(|&(_, _, w)| *w).sum()
+    }
+
+    #[test]
+    fn can_find_mst_with_kruskal() {
+        let g = weighted_test_graph();
+        let tree = kruskal(&g, |w| *w);
+
+        assert_eq!(tree.len(), 4);
+        assert_eq!(total(&tree), 11);
+    }
+
+    #[test]
+    fn can_find_mst_with_prim() {
+        let g = weighted_test_graph();
+        let tree = prim(&g, |w| *w);
+
+        assert_eq!(tree.len(), 4);
+        assert_eq!(total(&tree), 11);
+    }
+
+    #[test]
+    fn can_find_mst_with_boruvka() {
+        let g = weighted_test_graph();
+        let tree = boruvka(&g, |w| *w);
+
+        assert_eq!(tree.len(), 4);
+        assert_eq!(total(&tree), 11);
+    }
+
+    #[test]
+    fn can_handle_equal_weights() {
+        let g = weighted_test_graph();
+
+        assert_eq!(kruskal(&g, |_| 1).len(), 4);
+        assert_eq!(prim(&g, |_| 1).len(), 4);
+        assert_eq!(boruvka(&g, |_| 1).len(), 4);
+    }
+
+    #[test]
+    fn can_build_tree_graph() {
+        let g = weighted_test_graph();
+        let tree = to_graph(&g, &kruskal(&g, |w| *w));
+
+        assert_eq!(tree.node_count(), 6);
+        assert_eq!(tree.edge_count(), 4);
+        assert_eq!(tree.edge(2, 0).unwrap().value(), &1);
+        assert_eq!(tree.edge(2, 3), None);
+        assert_eq!(tree.connected_components().len(), 2);
+    }
+}
@@ -0,0 +1,524 @@
+use crate::algo::Measure;
+use crate::matrix::AdjacencyMatrix;
+use crate::visit::{
+    GraphBase, IntoEdges, IntoNeighbors, IntoNodeIdentifiers, NodeCount, NodeIndexable, NodeMap,
+    VisitMap, Visitable,
+};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::marker::PhantomData;
+
+pub type PathsOf<G, W> = ShortestPaths<
+    W,
+    <G as GraphBase>::NodeId,
+    <G as Visitable>::NodeMap<W>,
+    <G as Visitable>::NodeMap<<G as GraphBase>::NodeId>,
+>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortestPaths<W, K = usize, D = Vec<Option<W>>, P = Vec<Option<K>>> {
+    source: K,
+    distances: D,
+    predecessors: P,
+    marker: PhantomData<W>,
+}
+
+impl<W, K, D, P> ShortestPaths<W, K, D, P>
+where
+    W: Copy,
+    K: Copy + Eq,
+    D: NodeMap<K, W>,
+    P: NodeMap<K, K>,
+{
+    fn new(source: K, zero: W, mut distances: D, predecessors: P) -> Self {
+        distances.insert(source, zero);
+
+        Self {
+            source,
+            distances,
+            predecessors,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn source(&self) -> K {
+        self.source
+    }
+
+    pub fn distance(&self, target: K) -> Option<W> {
+        self.distances.get(target).copied()
+    }
+
+    pub fn predecessor(&self, target: K) -> Option<K> {
+        self.predecessors.get(target).copied()
+    }
+
+    pub fn distances(&self) -> &D {
+        &self.distances
+    }
+
+    pub fn predecessors(&self) -> &P {
+        &self.predecessors
+    }
+
+    pub fn path_to(&self, target: K) -> Option<Vec<K>> {
+        self.distance(target)?;
+
+        let mut path = vec![target];
+        let mut current = target;
+        while let Some(previous) = self.predecessor(current) {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    fn relax(&mut self, from: K, to: K, distance: W) {
+        self.distances.insert(to, distance);
+        self.predecessors.insert(to, from);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NegativeCycle<K = usize> {
+    nodes: Vec<K>,
+}
+
+impl<K> NegativeCycle<K> {
+    pub fn nodes(&self) -> &[K] {
+        &self.nodes
+    }
+}
+
+pub fn bfs<G>(graph: G, start: G::NodeId) -> PathsOf<G, usize>
+where
+    G: IntoNeighbors + Visitable,
+{
+    let mut paths = ShortestPaths::new(start, 0, graph.node_map(), graph.node_map());
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = paths.distance(node).unwrap();
+        for next in graph.neighbors(node) {
+            if paths.distance(next).is_none() {
+                paths.relax(node, next, distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+pub fn dijkstra<G, W, F>(graph: G, start: G::NodeId, weight: F) -> PathsOf<G, W>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Ord,
+    W: Measure,
+    F: FnMut(G::EdgeWeight) -> W,
+{
+    astar(graph, start, None, weight, |_| W::default())
+}
+
+pub fn astar<G, W, F, H>(
+    graph: G,
+    start: G::NodeId,
+    goal: Option<G::NodeId>,
+    mut weight: F,
+    mut heuristic: H,
+) -> PathsOf<G, W>
+where
+    G: IntoEdges + Visitable,
+    G::NodeId: Ord,
+    W: Measure,
+    F: FnMut(G::EdgeWeight) -> W,
+    H: FnMut(G::NodeId) -> W,
+{
+    let mut paths = ShortestPaths::new(start, W::default(), graph.node_map(), graph.node_map());
+    let mut finished = graph.visit_map();
+    let mut queue = BinaryHeap::from([Reverse((heuristic(start), start))]);
+
+    while let Some(Reverse((_, node))) = queue.pop() {
+        if !finished.visit(node) {
+            continue;
+        }
+
+        if goal == Some(node) {
+            break;
+        }
+
+        let distance = paths.distance(node).unwrap();
+        for (next, e) in graph.edges(node) {
+            let candidate = distance + weight(e);
+            let better = match paths.distance(next) {
+                Some(current) => candidate < current,
+                None => true,
+            };
+
+            if better && !finished.is_visited(next) {
+                paths.relax(node, next, candidate);
+                queue.push(Reverse((candidate + heuristic(next), next)));
+            }
+        }
+    }
+
+    paths
+}
+
+pub fn bellman_ford<G, W, F>(
+    graph: G,
+    start: G::NodeId,
+    mut weight: F,
+) -> Result<PathsOf<G, W>, NegativeCycle<G::NodeId>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeCount + Visitable,
+    W: Measure,
+    F: FnMut(G::EdgeWeight) -> W,
+{
+    let arcs: Vec<(G::NodeId, G::NodeId, W)> = graph
+        .node_identifiers()
+        .flat_map(|a| graph.edges(a).map(move |(b, e)| (a, b, e)))
+        .map(|(a, b, e)| (a, b, weight(e)))
+        .collect();
+    let mut paths = ShortestPaths::new(start, W::default(), graph.node_map(), graph.node_map());
+
+    let relax = |paths: &mut PathsOf<G, W>| {
+        let mut relaxed = None;
+        for &(a, b, w) in &arcs {
+            if let Some(distance) = paths.distance(a) {
+                let candidate = distance + w;
+                if paths.distance(b).is_none_or(|current| candidate < current) {
+                    paths.relax(a, b, candidate);
+                    relaxed = Some(b);
+                }
+            }
+        }
+
+        relaxed
+    };
+
+    for _ in 1..graph.node_count() {
+        if relax(&mut paths).is_none() {
+            return Ok(paths);
+        }
+    }
+
+    match relax(&mut paths) {
+        None => Ok(paths),
+        Some(mut index) => {
+            for _ in 0..graph.node_count() {
+                index = paths.predecessor(index).unwrap();
+            }
+
+            let mut nodes = vec![index];
+            let mut current = paths.predecessor(index).unwrap();
+            while current != index {
+                nodes.push(current);
+                current = paths.predecessor(current).unwrap();
+            }
+            nodes.reverse();
+
+            Err(NegativeCycle { nodes })
+        }
+    }
+}
+
+pub fn floyd_warshall<G, W, F>(
+    graph: G,
+    mut weight: F,
+) -> Result<AdjacencyMatrix<Option<W>>, NegativeCycle<G::NodeId>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+    W: Measure,
+    F: FnMut(G::EdgeWeight) -> W,
+{
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|node| graph.to_index(node))
+        .collect();
+    let mut distances = AdjacencyMatrix::<Option<W>>::new();
+    let mut predecessors = AdjacencyMatrix::<Option<usize>>::new();
+    for _ in 0..graph.node_bound() {
+        distances.push_default();
+        predecessors.push_default();
+    }
+    nodes
+        .iter()
+        .for_each(|&i| distances.set(i, i, Some(W::default())));
+
+    for node in graph.node_identifiers() {
+        let a = graph.to_index(node);
+        for (next, e) in graph.edges(node) {
+            let b = graph.to_index(next);
+            let w = weight(e);
+            if distances.get(a, b).is_none_or(|current| w < current) {
+                distances.set(a, b, Some(w));
+                predecessors.set(a, b, Some(a));
+            }
+        }
+    }
+
+    for &k in &nodes {
+        for &i in &nodes {
+            let ik = match *distances.get(i, k) {
+                Some(ik) => ik,
+                None => continue,
+            };
+
+            for &j in &nodes {
+                if let Some(kj) = *distances.get(k, j) {
+                    let candidate = ik + kj;
+                    if distances
+                        .get(i, j)
+                        .is_none_or(|current| candidate < current)
+                    {
+                        distances.set(i, j, Some(candidate));
+                        predecessors.set(i, j, *predecessors.get(k, j));
+                    }
+                }
+            }
+        }
+    }
+
+    let row = match nodes
+        .iter()
+        .copied()
+        .find(|&i| distances.get(i, i).is_some_and(|d| d < W::default()))
+    {
+        Some(row) => row,
+        None => return Ok(distances),
+    };
+
+    let predecessor = |index: usize| predecessors.get(row, index).unwrap();
+    let mut index = row;
+    for _ in 0..nodes.len() {
+        index = predecessor(index);
+    }
+
+    let mut cycle = vec![graph.to_node(index)];
+    let mut current = predecessor(index);
+    while current != index {
+        cycle.push(graph.to_node(current));
+        current = predecessor(current);
+    }
+    cycle.reverse();
+
+    Err(NegativeCycle { nodes: cycle })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Graph;
+    use crate::visit::ImplicitGraph;
+
+    fn grid_test_graph(w: usize, h: usize) -> Graph<(), u32> {
+        let mut g = Graph::<(), u32>::new_undirected();
+        for _ in 0..(w * h) {
+            g.add_node(());
+        }
+        for y in 0..h {
+            for x in 1..w {
+                g.set_edge(y * w + x, y * w + x - 1, 1);
+            }
+        }
+        for y in 1..h {
+            for x in 0..w {
+                g.set_edge(y * w + x, (y - 1) * w + x, 1);
+            }
+        }
+
+        g
+    }
+
+    fn weighted_test_graph() -> Graph<(), u32> {
+        let mut g = Graph::<(), u32>::new_directed();
+        for _ in 0..5 {
+            g.add_node(());
+        }
+        g.set_edge(0, 1, 10);
+        g.set_edge(0, 2, 3);
+        g.set_edge(2, 1, 4);
+        g.set_edge(1, 3, 2);
+        g.set_edge(2, 3, 8);
+        g.set_edge(3, 0, 1);
+
+        g
+    }
+
+    #[test]
+    fn can_find_bfs_distances() {
+        let g = grid_test_graph(3, 3);
+        let paths = bfs(&g, 0);
+
+        assert_eq!(paths.distance(0), Some(0));
+        assert_eq!(paths.distance(4), Some(2));
+        assert_eq!(paths.distance(8), Some(4));
+        assert_eq!(paths.path_to(8).unwrap().len(), 5);
+        assert_eq!(paths.path_to(2), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn can_index_distances_densely() {
+        let g = grid_test_graph(2, 2);
+        let paths = dijkstra(&g, 0, |e| *e);
+
+        let distances: &[Option<u32>] = paths.distances();
+        assert_eq!(distances, &[Some(0), Some(1), Some(1), Some(2)]);
+        assert_eq!(paths.predecessors()[0], None);
+        assert_eq!(paths.distance(4), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "node index 4 is out of bounds for a graph with 4 nodes")]
+    fn can_reject_invalid_start() {
+        let g = grid_test_graph(2, 2);
+        bfs(&g, 4);
+    }
+
+    #[test]
+    fn can_find_dijkstra_distances() {
+        let g = weighted_test_graph();
+        let paths = dijkstra(&g, 0, |e| *e);
+
+        assert_eq!(paths.distance(1), Some(7));
+        assert_eq!(paths.distance(3), Some(9));
+        assert_eq!(paths.distance(4), None);
+        assert_eq!(paths.path_to(3), Some(vec![0, 2, 1, 3]));
+        assert_eq!(paths.path_to(4), None);
+    }
+
+    #[test]
+    fn can_respect_direction() {
+        let g = weighted_test_graph();
+        let paths = bfs(&g, 1);
+
+        assert_eq!(paths.distance(3), Some(1));
+        assert_eq!(paths.distance(0), Some(2));
+        assert_eq!(paths.path_to(2), Some(vec![1, 3, 0, 2]));
+    }
+
+    #[test]
+    fn can_find_astar_path() {
+        let w = 5;
+        let g = grid_test_graph(w, 5);
+        let goal = 24;
+        let manhattan =
+            |index: usize| ((goal % w).abs_diff(index % w) + (goal / w).abs_diff(index / w)) as u32;
+        let paths = astar(&g, 0, Some(goal), |e| *e, manhattan);
+
+        assert_eq!(paths.distance(goal), Some(8));
+        assert_eq!(paths.path_to(goal).unwrap().len(), 9);
+        assert_eq!(paths.path_to(goal).unwrap()[0], 0);
+    }
+
+    #[test]
+    fn can_find_bellman_ford_distances() {
+        let g = weighted_test_graph();
+        let mut signed = Graph::<(), i32>::new_directed();
+        g.nodes().for_each(|_| signed.add_node(()));
+        g.edges()
+            .for_each(|e| signed.set_edge(e.index_a(), e.index_b(), *e.value() as i32));
+        signed.set_edge(1, 4, 5);
+        signed.set_edge(4, 3, -6);
+
+        let paths = bellman_ford(&signed, 0, |e| *e).unwrap();
+        assert_eq!(paths.distance(4), Some(12));
+        assert_eq!(paths.distance(3), Some(6));
+        assert_eq!(paths.path_to(3), Some(vec![0, 2, 1, 4, 3]));
+    }
+
+    #[test]
+    fn can_detect_negative_cycle() {
+        let mut g = Graph::<(), i32>::new_directed();
+        for _ in 0..4 {
+            g.add_node(());
+        }
+        g.set_edge(0, 1, 1);
+        g.set_edge(1, 2, -1);
+        g.set_edge(2, 3, -1);
+        g.set_edge(3, 1, 3);
+
+        assert_eq!(bellman_ford(&g, 0, |e| *e).unwrap().distance(3), Some(-1));
+        assert!(floyd_warshall(&g, |e| *e).is_ok());
+
+        g.set_edge(3, 1, -1);
+        let cycle = bellman_ford(&g, 0, |e| *e).unwrap_err();
+        let mut nodes = cycle.nodes().to_vec();
+        nodes.sort();
+        assert_eq!(nodes, vec![1, 2, 3]);
+
+        let cycle = floyd_warshall(&g, |e| *e).unwrap_err();
+        assert_eq!(cycle.nodes().len(), 3);
+        for (i, &a) in cycle.nodes().iter().enumerate() {
+            let b = cycle.nodes()[(i + 1) % cycle.nodes().len()];
+            assert!(g.edge(a, b).is_some());
+        }
+    }
+
+    #[test]
+    fn can_detect_unreachable_negative_cycle() {
+        let mut g = Graph::<(), i32>::new_directed();
+        for _ in 0..4 {
+            g.add_node(());
+        }
+        g.set_edge(0, 1, 2);
+        g.set_edge(2, 3, -3);
+        g.set_edge(3, 2, 1);
+
+        assert!(bellman_ford(&g, 0, |e| *e).is_ok());
+        let cycle = floyd_warshall(&g, |e| *e).unwrap_err();
+        let mut nodes = cycle.nodes().to_vec();
+        nodes.sort();
+        assert_eq!(nodes, vec![2, 3]);
+    }
+
+    #[test]
+    fn can_find_all_pairs_distances() {
+        let g = weighted_test_graph();
+        let distances = floyd_warshall(&g, |e| *e).unwrap();
+
+        for start in 0..g.node_count() {
+            let paths = dijkstra(&g, start, |e| *e);
+            for target in 0..g.node_count() {
+                assert_eq!(*distances.get(start, target), paths.distance(target));
+            }
+        }
+        assert_eq!(*distances.get(3, 1), Some(8));
+    }
+
+    #[test]
+    fn can_search_implicit_grid() {
+        let wall = |(x, y): (i64, i64)| x == 2 && y < 3;
+        let grid = ImplicitGraph::new(move |(x, y): (i64, i64)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(move |&next| !wall(next))
+                .map(|next| (next, 1u32))
+        });
+
+        let goal: (i64, i64) = (4, 0);
+        let manhattan = |(x, y): (i64, i64)| (goal.0.abs_diff(x) + goal.1.abs_diff(y)) as u32;
+        let paths = astar(&grid, (0, 0), Some(goal), |w| w, manhattan);
+
+        assert_eq!(paths.distance(goal), Some(10));
+        let path = paths.path_to(goal).unwrap();
+        assert_eq!(path.len(), 11);
+        assert!(path.contains(&(2, 3)));
+    }
+
+    #[test]
+    fn can_find_implicit_distances() {
+        let ring = ImplicitGraph::new(|n: u8| [((n + 1) % 6, 1u32), ((n + 3) % 6, 5)]);
+
+        let paths = bfs(&ring, 0);
+        assert_eq!(paths.distance(3), Some(1));
+        assert_eq!(paths.distances().len(), 6);
+
+        let paths = dijkstra(&ring, 0, |w| w);
+        assert_eq!(paths.distance(3), Some(3));
+        assert_eq!(paths.path_to(5), Some(vec![0, 1, 2, 3, 4, 5]));
+    }
+}
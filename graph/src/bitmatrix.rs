@@ -0,0 +1,440 @@
+use crate::graph::Graph;
+use crate::storage::GraphStorage;
+
+const BITS: usize = u64::BITS as usize;
+
+pub type UnweightedGraph<N> = Graph<N, (), BitMatrix>;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BitMatrix {
+    rows: Vec<Vec<u64>>,
+    // transposed rows of a directed matrix, so incoming edges are a bit scan too
+    columns: Vec<Vec<u64>>,
+    symmetric: bool,
+}
+
+fn words(size: usize) -> usize {
+    size.div_ceil(BITS)
+}
+
+fn bits(index: usize, mut word: u64) -> impl Iterator<Item = usize> {
+    std::iter::from_fn(move || match word {
+        0 => None,
+        _ => {
+            let bit = word.trailing_zeros() as usize;
+            word &= word - 1;
+            Some(index * BITS + bit)
+        }
+    })
+}
+
+fn ones(row: &[u64]) -> impl Iterator<Item = usize> + '_ {
+    row.iter().enumerate().flat_map(|(i, &word)| bits(i, word))
+}
+
+fn transpose(rows: &[Vec<u64>]) -> Vec<Vec<u64>> {
+    let mut columns = vec![vec![0; words(rows.len())]; rows.len()];
+    for (a, row) in rows.iter().enumerate() {
+        for b in ones(row) {
+            columns[b][a / BITS] |= 1 << (a % BITS);
+        }
+    }
+
+    columns
+}
+
+fn remove_bit(row: &mut [u64], index: usize) {
+    let (word, bit) = (index / BITS, index % BITS);
+    let low = (1u64 << bit) - 1;
+    row[word] = (row[word] & low) | ((row[word] >> 1) & !low);
+    for i in word + 1..row.len() {
+        row[i - 1] |= (row[i] & 1) << (BITS - 1);
+        row[i] >>= 1;
+    }
+}
+
+impl BitMatrix {
+    pub fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            columns: Vec::new(),
+            symmetric: false,
+        }
+    }
+
+    pub fn new_symmetric() -> Self {
+        Self {
+            rows: Vec::new(),
+            columns: Vec::new(),
+            symmetric: true,
+        }
+    }
+
+    pub fn is_symmetric(&self) -> bool {
+        self.symmetric
+    }
+
+    pub fn size(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn contains(&self, a: usize, b: usize) -> bool {
+        self.rows[a][b / BITS] & (1 << (b % BITS)) != 0
+    }
+
+    pub fn insert(&mut self, a: usize, b: usize) -> bool {
+        assert!(a < self.size() && b < self.size());
+
+        let present = self.contains(a, b);
+        self.rows[a][b / BITS] |= 1 << (b % BITS);
+        match self.symmetric {
+            true => self.rows[b][a / BITS] |= 1 << (a % BITS),
+            false => self.columns[b][a / BITS] |= 1 << (a % BITS),
+        }
+
+        !present
+    }
+
+    pub fn erase(&mut self, a: usize, b: usize) -> bool {
+        if a >= self.size() || b >= self.size() || !self.contains(a, b) {
+            return false;
+        }
+
+        self.rows[a][b / BITS] &= !(1 << (b % BITS));
+        match self.symmetric {
+            true => self.rows[b][a / BITS] &= !(1 << (a % BITS)),
+            false => self.columns[b][a / BITS] &= !(1 << (a % BITS)),
+        }
+
+        true
+    }
+
+    pub fn row(&self, index: usize) -> &[u64] {
+        &self.rows[index]
+    }
+
+    pub fn common_neighbors(&self, a: usize, b: usize) -> impl Iterator<Item = usize> + '_ {
+        self.rows[a]
+            .iter()
+            .zip(self.rows[b].iter())
+            .enumerate()
+            .flat_map(|(i, (x, y))| bits(i, x & y))
+    }
+
+    pub fn transitive_closure(&self) -> BitMatrix {
+        let mut closure = self.clone();
+        for k in 0..closure.size() {
+            let through = closure.rows[k].clone();
+            for i in 0..closure.size() {
+                if closure.contains(i, k) {
+                    closure.rows[i]
+                        .iter_mut()
+                        .zip(through.iter())
+                        .for_each(|(word, other)| *word |= other);
+                }
+            }
+        }
+        if !closure.symmetric {
+            closure.columns = transpose(&closure.rows);
+        }
+
+        closure
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        let rows = match self.symmetric {
+            true => self.rows.clone(),
+            false => self
+                .rows
+                .iter()
+                .zip(self.columns.iter())
+                .map(|(row, column)| row.iter().zip(column).map(|(x, y)| x | y).collect())
+                .collect(),
+        };
+
+        let mut count = 0;
+        for i in 0..rows.len() {
+            for j in ones(&rows[i]).take_while(|&j| j < i) {
+                let (full, partial) = (j / BITS, j % BITS);
+                count += (0..full)
+                    .map(|w| (rows[i][w] & rows[j][w]).count_ones() as usize)
+                    .sum::<usize>();
+                let mask = (1u64 << partial) - 1;
+                count += (rows[i][full] & rows[j][full] & mask).count_ones() as usize;
+            }
+        }
+
+        count
+    }
+
+    fn entry(&self, a: usize, b: usize) -> (usize, usize, &'static ()) {
+        match self.symmetric && a < b {
+            true => (b, a, &()),
+            false => (a, b, &()),
+        }
+    }
+}
+
+impl GraphStorage<()> for BitMatrix {
+    fn new_undirected() -> Self {
+        Self::new_symmetric()
+    }
+
+    fn is_symmetric(&self) -> bool {
+        self.symmetric
+    }
+
+    fn node_count(&self) -> usize {
+        self.size()
+    }
+
+    fn push_default(&mut self) {
+        let len = words(self.size() + 1);
+        self.rows.iter_mut().for_each(|row| row.resize(len, 0));
+        self.rows.push(vec![0; len]);
+        if !self.symmetric {
+            self.columns
+                .iter_mut()
+                .for_each(|column| column.resize(len, 0));
+            self.columns.push(vec![0; len]);
+        }
+    }
+
+    fn remove(&mut self, index: usize) {
+        assert!(index < self.size());
+
+        self.rows.remove(index);
+        if !self.symmetric {
+            self.columns.remove(index);
+        }
+        let len = words(self.size());
+        for row in self.rows.iter_mut().chain(self.columns.iter_mut()) {
+            remove_bit(row, index);
+            row.truncate(len);
+        }
+    }
+
+    fn set(&mut self, a: usize, b: usize, _: ()) {
+        self.insert(a, b);
+    }
+
+    fn unset(&mut self, a: usize, b: usize) -> Option<()> {
+        self.erase(a, b).then_some(())
+    }
+
+    fn get(&self, a: usize, b: usize) -> Option<&()> {
+        (a < self.size() && b < self.size() && self.contains(a, b)).then_some(&())
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (usize, usize, &())> + '_> {
+        let symmetric = self.symmetric;
+        let iter = self.rows.iter().enumerate().flat_map(move |(a, row)| {
+            ones(row)
+                .take_while(move |&b| !symmetric || b <= a)
+                .map(move |b| (a, b, &()))
+        });
+
+        Box::new(iter)
+    }
+
+    fn iter_adjacent(&self, index: usize) -> Box<dyn Iterator<Item = (usize, usize, &())> + '_> {
+        let outgoing = ones(&self.rows[index]).map(move |b| self.entry(index, b));
+
+        match self.symmetric {
+            true => Box::new(outgoing),
+            false => Box::new(
+                outgoing.chain(
+                    self.iter_incoming(index)
+                        .filter(move |(a, _, _)| *a != index),
+                ),
+            ),
+        }
+    }
+
+    fn iter_outgoing(&self, index: usize) -> Box<dyn Iterator<Item = (usize, usize, &())> + '_> {
+        let iter = ones(&self.rows[index])
+            .map(move |b| self.entry(index, b))
+            .filter(move |(a, _, _)| *a == index);
+
+        Box::new(iter)
+    }
+
+    fn iter_incoming(&self, index: usize) -> Box<dyn Iterator<Item = (usize, usize, &())> + '_> {
+        match self.symmetric {
+            true => Box::new(
+                ones(&self.rows[index])
+                    .map(move |b| self.entry(index, b))
+                    .filter(move |(_, b, _)| *b == index),
+            ),
+            false => Box::new(ones(&self.columns[index]).map(move |a| (a, index, &()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_set() -> BitMatrix {
+        let mut matrix = BitMatrix::new();
+        for _ in 0..70 {
+            matrix.push_default();
+        }
+        matrix.set(0, 1, ());
+        matrix.set(1, 65, ());
+        matrix.set(65, 0, ());
+        matrix.set(69, 69, ());
+
+        matrix
+    }
+
+    #[test]
+    fn can_get_and_set() {
+        let mut matrix = create_set();
+
+        assert_eq!(matrix.get(0, 1), Some(&()));
+        assert_eq!(matrix.get(1, 0), None);
+        assert_eq!(matrix.get(1, 65), Some(&()));
+        assert_eq!(matrix.get(70, 0), None);
+
+        assert_eq!(matrix.unset(1, 65), Some(()));
+        assert_eq!(matrix.unset(1, 65), None);
+        assert_eq!(matrix.iter().count(), 3);
+    }
+
+    #[test]
+    fn can_remove() {
+        let mut matrix = create_set();
+        matrix.remove(1);
+
+        assert_eq!(matrix.size(), 69);
+        assert_eq!(matrix.row(0).len(), 2);
+        let edges: Vec<(usize, usize)> = matrix.iter().map(|(a, b, _)| (a, b)).collect();
+        assert_eq!(edges, vec![(64, 0), (68, 68)]);
+        let incoming: Vec<(usize, usize)> =
+            matrix.iter_incoming(0).map(|(a, b, _)| (a, b)).collect();
+        assert_eq!(incoming, vec![(64, 0)]);
+
+        for _ in 0..5 {
+            matrix.remove(0);
+        }
+        assert_eq!(matrix.row(0).len(), 1);
+        let edges: Vec<(usize, usize)> = matrix.iter().map(|(a, b, _)| (a, b)).collect();
+        assert_eq!(edges, vec![(63, 63)]);
+    }
+
+    #[test]
+    fn can_iter_adjacent() {
+        let matrix = create_set();
+
+        for index in [0, 1, 65, 69] {
+            let expected: Vec<_> = matrix
+                .iter()
+                .filter(|(a, b, _)| *a == index || *b == index)
+                .collect();
+            let mut adjacent: Vec<_> = matrix.iter_adjacent(index).collect();
+            adjacent.sort();
+            assert_eq!(adjacent, expected);
+        }
+    }
+
+    #[test]
+    fn can_store_symmetric() {
+        let mut g = Graph::<(), (), BitMatrix>::new_undirected();
+        for _ in 0..4 {
+            g.add_node(());
+        }
+        g.set_edge(0, 1, ());
+        g.set_edge(2, 1, ());
+        g.set_edge(3, 3, ());
+
+        assert!(g.edge(1, 0).is_some());
+        assert_eq!(g.edge_count(), 3);
+        let neighbors: Vec<usize> = g.node(1).unwrap().neighbors().map(|n| n.index()).collect();
+        assert_eq!(neighbors, vec![0, 2]);
+        assert!(!g.is_connected() && g.is_cyclic());
+
+        g.remove_edge(0, 1);
+        assert_eq!(g.edge_count(), 2);
+        assert!(g.edge(0, 1).is_none());
+    }
+
+    #[test]
+    fn can_use_unweighted_graph() {
+        let mut g = UnweightedGraph::<&str>::new_directed();
+        g.add_node("A");
+        g.add_node("B");
+        g.add_node("C");
+        g.set_edge(0, 1, ());
+        g.set_edge(2, 1, ());
+        g.set_edge(1, 0, ());
+
+        let node = g.node(1).unwrap();
+        let successors: Vec<usize> = node.successors().map(|n| n.index()).collect();
+        let mut predecessors: Vec<usize> = node.predecessors().map(|n| n.index()).collect();
+        predecessors.sort();
+        assert_eq!(successors, vec![0]);
+        assert_eq!(predecessors, vec![0, 2]);
+        assert_eq!(g.edge_count(), 3);
+
+        g.remove_node(0);
+        assert_eq!(g.edge_count(), 1);
+        assert_eq!(g.node(0).unwrap().in_degree(), 1);
+        assert_eq!(g.node(1).unwrap().value(), &"C");
+    }
+
+    #[test]
+    fn can_intersect_rows() {
+        let mut matrix = BitMatrix::new_symmetric();
+        for _ in 0..100 {
+            matrix.push_default();
+        }
+        for b in [3, 64, 70, 99] {
+            matrix.set(b, 0, ());
+        }
+        for b in [3, 70, 98] {
+            matrix.set(b, 1, ());
+        }
+
+        let common: Vec<usize> = matrix.common_neighbors(0, 1).collect();
+        assert_eq!(common, vec![3, 70]);
+    }
+
+    #[test]
+    fn can_compute_transitive_closure() {
+        let mut matrix = BitMatrix::new();
+        for _ in 0..4 {
+            matrix.push_default();
+        }
+        matrix.set(0, 1, ());
+        matrix.set(1, 2, ());
+        matrix.set(2, 1, ());
+
+        let closure = matrix.transitive_closure();
+        let edges: Vec<(usize, usize)> = closure.iter().map(|(a, b, _)| (a, b)).collect();
+        assert_eq!(edges, vec![(0, 1), (0, 2), (1, 1), (1, 2), (2, 1), (2, 2)]);
+        let incoming: Vec<usize> = closure.iter_incoming(2).map(|(a, _, _)| a).collect();
+        assert_eq!(incoming, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn can_count_triangles() {
+        let mut matrix = BitMatrix::new_symmetric();
+        for _ in 0..5 {
+            matrix.push_default();
+        }
+        for (a, b) in [(1, 0), (2, 0), (2, 1), (3, 1), (3, 2), (4, 4)] {
+            matrix.set(a, b, ());
+        }
+        assert_eq!(matrix.triangle_count(), 2);
+
+        let mut directed = BitMatrix::new();
+        for _ in 0..3 {
+            directed.push_default();
+        }
+        directed.set(0, 1, ());
+        directed.set(1, 2, ());
+        directed.set(0, 2, ());
+        assert_eq!(directed.triangle_count(), 1);
+    }
+}
@@ -0,0 +1,231 @@
+use crate::graph::Graph;
+use crate::storage::GraphStorage;
+use std::fmt::Debug;
+
+#[derive(Clone, PartialEq)]
+pub struct CsrGraph<N, E> {
+    nodes: Vec<N>,
+    edges: Vec<(usize, usize, E)>,
+    offsets: Vec<usize>,
+    neighbors: Vec<usize>,
+    edge_ids: Vec<usize>,
+    directed: bool,
+}
+
+impl<N, E, S> Graph<N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    pub fn freeze(self) -> CsrGraph<N, E> {
+        let (mut storage, nodes, directed) = self.into_parts();
+
+        let mut coordinates: Vec<(usize, usize)> = storage.iter().map(|(a, b, _)| (a, b)).collect();
+        coordinates.sort_unstable();
+        let edges: Vec<(usize, usize, E)> = coordinates
+            .into_iter()
+            .map(|(a, b)| (a, b, storage.unset(a, b).unwrap()))
+            .collect();
+
+        let mut offsets = vec![0; nodes.len() + 1];
+        for &(a, b, _) in &edges {
+            offsets[a + 1] += 1;
+            if !directed && a != b {
+                offsets[b + 1] += 1;
+            }
+        }
+        for i in 1..offsets.len() {
+            offsets[i] += offsets[i - 1];
+        }
+
+        let mut cursors = offsets.clone();
+        let mut slots = vec![(0, 0); offsets[nodes.len()]];
+        for (id, &(a, b, _)) in edges.iter().enumerate() {
+            slots[cursors[a]] = (b, id);
+            cursors[a] += 1;
+            if !directed && a != b {
+                slots[cursors[b]] = (a, id);
+                cursors[b] += 1;
+            }
+        }
+        for window in offsets.windows(2) {
+            slots[window[0]..window[1]].sort_unstable();
+        }
+
+        CsrGraph {
+            nodes,
+            edges,
+            offsets,
+            neighbors: slots.iter().map(|&(neighbor, _)| neighbor).collect(),
+            edge_ids: slots.iter().map(|&(_, id)| id).collect(),
+            directed,
+        }
+    }
+}
+
+impl<N, E> CsrGraph<N, E> {
+    pub fn thaw<S>(self) -> Graph<N, E, S>
+    where
+        S: GraphStorage<E>,
+    {
+        let mut graph = match self.directed {
+            true => Graph::new_directed(),
+            false => Graph::new_undirected(),
+        };
+        self.nodes.into_iter().for_each(|n| graph.add_node(n));
+        self.edges
+            .into_iter()
+            .for_each(|(a, b, e)| graph.set_edge(a, b, e));
+
+        graph
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn node(&self, index: usize) -> Option<&N> {
+        self.nodes.get(index)
+    }
+
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn edge(&self, index_a: usize, index_b: usize) -> Option<&E> {
+        if index_a >= self.node_count() {
+            return None;
+        }
+
+        let row = self.offsets[index_a]..self.offsets[index_a + 1];
+        self.neighbors[row.clone()]
+            .binary_search(&index_b)
+            .ok()
+            .map(|position| &self.edges[self.edge_ids[row.start + position]].2)
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, &E)> + '_ {
+        self.edges.iter().map(|(a, b, e)| (*a, *b, e))
+    }
+
+    pub fn neighbors(&self, index: usize) -> &[usize] {
+        &self.neighbors[self.offsets[index]..self.offsets[index + 1]]
+    }
+
+    pub fn out_edges(&self, index: usize) -> impl Iterator<Item = (usize, &E)> + '_ {
+        let row = self.offsets[index]..self.offsets[index + 1];
+
+        self.neighbors[row.clone()]
+            .iter()
+            .zip(self.edge_ids[row].iter())
+            .map(|(&neighbor, &id)| (neighbor, &self.edges[id].2))
+    }
+
+    pub fn degree(&self, index: usize) -> usize {
+        self.offsets[index + 1] - self.offsets[index]
+    }
+}
+
+impl<N, E> Debug for CsrGraph<N, E>
+where
+    N: Debug,
+    E: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Nodes:")?;
+        f.debug_map()
+            .entries(self.nodes.iter().enumerate())
+            .finish()?;
+        f.write_str("\n")?;
+        f.write_str("Edges:")?;
+        f.debug_list().entries(self.edges()).finish()?;
+        f.write_str("\n")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::list::AdjacencyList;
+
+    fn directed_test_graph() -> Graph<String, u8> {
+        let mut g = Graph::<String, u8>::new_directed();
+        for name in ["A", "B", "C", "D"] {
+            g.add_node(name.to_owned());
+        }
+        g.set_edge(0, 2, 2);
+        g.set_edge(0, 1, 1);
+        g.set_edge(2, 0, 20);
+        g.set_edge(3, 3, 33);
+
+        g
+    }
+
+    #[test]
+    fn can_freeze_directed() {
+        let csr = directed_test_graph().freeze();
+
+        assert!(csr.is_directed());
+        assert_eq!(csr.node_count(), 4);
+        assert_eq!(csr.edge_count(), 4);
+        assert_eq!(csr.node(1).unwrap(), "B");
+        assert_eq!(csr.neighbors(0), &[1, 2]);
+        assert_eq!(csr.neighbors(1), &[] as &[usize]);
+        assert_eq!(csr.degree(0), 2);
+        assert_eq!(csr.degree(3), 1);
+        assert_eq!(csr.edge(2, 0), Some(&20));
+        assert_eq!(csr.edge(1, 0), None);
+        assert_eq!(csr.edge(4, 0), None);
+
+        let out: Vec<(usize, u8)> = csr.out_edges(0).map(|(b, e)| (b, *e)).collect();
+        assert_eq!(out, vec![(1, 1), (2, 2)]);
+    }
+
+    #[test]
+    fn can_freeze_undirected() {
+        let mut g = Graph::<(), u8, AdjacencyList<u8>>::new_undirected();
+        for _ in 0..4 {
+            g.add_node(());
+        }
+        g.set_edge(0, 1, 1);
+        g.set_edge(2, 1, 21);
+        g.set_edge(3, 1, 31);
+        g.set_edge(2, 2, 22);
+
+        let csr = g.freeze();
+        assert!(!csr.is_directed());
+        assert_eq!(csr.edge_count(), 4);
+        assert_eq!(csr.neighbors(1), &[0, 2, 3]);
+        assert_eq!(csr.neighbors(2), &[1, 2]);
+        assert_eq!(csr.degree(1), 3);
+        assert_eq!(csr.edge(1, 2), Some(&21));
+        assert_eq!(csr.edge(2, 1), Some(&21));
+    }
+
+    #[test]
+    fn can_thaw() {
+        let g = directed_test_graph();
+        let thawed: Graph<String, u8> = g.clone().freeze().thaw();
+        assert_eq!(thawed, g);
+
+        let mut g = Graph::<u8, ()>::new_undirected();
+        g.add_node(0);
+        g.add_node(1);
+        g.set_edge(0, 1, ());
+        let mut thawed: Graph<u8, (), AdjacencyList<()>> = g.freeze().thaw();
+        assert!(thawed.edge(1, 0).is_some());
+
+        thawed.add_node(2);
+        thawed.set_edge(2, 0, ());
+        assert_eq!(thawed.edge_count(), 2);
+    }
+}
@@ -0,0 +1,525 @@
+use crate::error::ParseError;
+use crate::graph::Graph;
+use crate::storage::GraphStorage;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+
+    quoted
+}
+
+impl<N, E, S> Graph<N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    pub fn to_dot<FN, FE>(&self, mut node_label: FN, mut edge_label: FE) -> String
+    where
+        FN: FnMut(&N) -> String,
+        FE: FnMut(&E) -> String,
+    {
+        let (keyword, op) = match self.is_directed() {
+            true => ("digraph", "->"),
+            false => ("graph", "--"),
+        };
+
+        let mut dot = String::new();
+        writeln!(dot, "{} {{", keyword).unwrap();
+        for n in self.nodes() {
+            writeln!(
+                dot,
+                "    {} [label={}];",
+                n.index(),
+                quote(&node_label(n.value()))
+            )
+            .unwrap();
+        }
+        for e in self.edges() {
+            writeln!(
+                dot,
+                "    {} {} {} [label={}];",
+                e.index_a(),
+                op,
+                e.index_b(),
+                quote(&edge_label(e.value()))
+            )
+            .unwrap();
+        }
+        dot.push_str("}\n");
+
+        dot
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Id(String),
+    LeftBrace,
+    RightBrace,
+    LeftBracket,
+    RightBracket,
+    Equals,
+    Semicolon,
+    Comma,
+    Colon,
+    Edge(bool),
+}
+
+type Spanned = (Token, usize, usize);
+
+struct Lexer<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            chars: input.chars().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        match c {
+            '\n' => {
+                self.line += 1;
+                self.column = 1;
+            }
+            _ => self.column += 1,
+        }
+
+        Some(c)
+    }
+
+    fn skip_trivia(&mut self) -> Result<(), ParseError> {
+        let mut line_start = self.line == 1 && self.column == 1;
+        while let Some(&c) = self.chars.peek() {
+            match c {
+                '\n' => {
+                    self.bump();
+                    line_start = true;
+                }
+                c if c.is_whitespace() => {
+                    self.bump();
+                }
+                '#' if line_start => {
+                    while self.chars.peek().is_some_and(|&c| c != '\n') {
+                        self.bump();
+                    }
+                }
+                '/' => {
+                    let mut ahead = self.chars.clone();
+                    ahead.next();
+                    match ahead.next() {
+                        Some('/') => {
+                            while self.chars.peek().is_some_and(|&c| c != '\n') {
+                                self.bump();
+                            }
+                        }
+                        Some('*') => {
+                            let (line, column) = (self.line, self.column);
+                            self.bump();
+                            self.bump();
+                            loop {
+                                match self.bump() {
+                                    Some('*') if self.chars.peek() == Some(&'/') => {
+                                        self.bump();
+                                        break;
+                                    }
+                                    Some(_) => {}
+                                    None => {
+                                        return Err(ParseError::new(
+                                            line,
+                                            column,
+                                            "unterminated comment",
+                                        ))
+                                    }
+                                }
+                            }
+                        }
+                        _ => return Ok(()),
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn tokenize(mut self) -> Result<(Vec<Spanned>, (usize, usize)), ParseError> {
+        let mut tokens = Vec::new();
+
+        loop {
+            self.skip_trivia()?;
+            let (line, column) = (self.line, self.column);
+            let c = match self.bump() {
+                Some(c) => c,
+                None => return Ok((tokens, (line, column))),
+            };
+
+            let token = match c {
+                '{' => Token::LeftBrace,
+                '}' => Token::RightBrace,
+                '[' => Token::LeftBracket,
+                ']' => Token::RightBracket,
+                '=' => Token::Equals,
+                ';' => Token::Semicolon,
+                ',' => Token::Comma,
+                ':' => Token::Colon,
+                '-' if self.chars.peek() == Some(&'>') => {
+                    self.bump();
+                    Token::Edge(true)
+                }
+                '-' if self.chars.peek() == Some(&'-') => {
+                    self.bump();
+                    Token::Edge(false)
+                }
+                '"' => {
+                    let mut value = String::new();
+                    loop {
+                        match self.bump() {
+                            Some('"') => break,
+                            Some('\\') => match self.bump() {
+                                Some('"') => value.push('"'),
+                                Some('\\') => value.push('\\'),
+                                Some('n') => value.push('\n'),
+                                Some('\n') => {}
+                                Some(c) => {
+                                    value.push('\\');
+                                    value.push(c);
+                                }
+                                None => {}
+                            },
+                            Some(c) => value.push(c),
+                            None => {
+                                return Err(ParseError::new(line, column, "unterminated string"))
+                            }
+                        }
+                    }
+                    Token::Id(value)
+                }
+                c if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' => {
+                    let mut value = String::from(c);
+                    while let Some(&c) = self.chars.peek() {
+                        if !(c.is_alphanumeric() || c == '_' || c == '.') {
+                            break;
+                        }
+                        value.push(c);
+                        self.bump();
+                    }
+                    Token::Id(value)
+                }
+                c => {
+                    return Err(ParseError::new(
+                        line,
+                        column,
+                        format!("unexpected character '{}'", c),
+                    ))
+                }
+            };
+
+            tokens.push((token, line, column));
+        }
+    }
+}
+
+struct Parser<S> {
+    tokens: Vec<Spanned>,
+    position: usize,
+    end: (usize, usize),
+    graph: Graph<String, String, S>,
+    ids: HashMap<String, usize>,
+}
+
+impl<S> Parser<S>
+where
+    S: GraphStorage<String>,
+{
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _, _)| token)
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        let (line, column) = match self.tokens.get(self.position) {
+            Some(&(_, line, column)) => (line, column),
+            None => self.end,
+        };
+
+        ParseError::new(line, column, message)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek().cloned();
+        self.position += 1;
+
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        match self.peek() == Some(token) {
+            true => {
+                self.position += 1;
+                true
+            }
+            false => false,
+        }
+    }
+
+    fn expect(&mut self, token: Token, what: &str) -> Result<(), ParseError> {
+        match self.eat(&token) {
+            true => Ok(()),
+            false => Err(self.error(format!("expected {}", what))),
+        }
+    }
+
+    fn id(&mut self) -> Result<String, ParseError> {
+        match self.peek() {
+            Some(Token::Id(_)) => match self.next() {
+                Some(Token::Id(id)) => Ok(id),
+                _ => unreachable!(),
+            },
+            _ => Err(self.error("expected identifier")),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(Token::Id(id)) if id.eq_ignore_ascii_case(keyword) => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn attributes(&mut self) -> Result<HashMap<String, String>, ParseError> {
+        let mut attributes = HashMap::new();
+        while self.eat(&Token::LeftBracket) {
+            while !self.eat(&Token::RightBracket) {
+                let key = self.id()?;
+                self.expect(Token::Equals, "'='")?;
+                let value = self.id()?;
+                attributes.insert(key, value);
+                if !self.eat(&Token::Comma) {
+                    self.eat(&Token::Semicolon);
+                }
+            }
+        }
+
+        Ok(attributes)
+    }
+
+    fn node(&mut self) -> Result<usize, ParseError> {
+        let id = self.id()?;
+        if self.peek() == Some(&Token::Colon) {
+            return Err(self.error("ports are not supported"));
+        }
+
+        let next = self.graph.node_count();
+        let index = *self.ids.entry(id.clone()).or_insert(next);
+        if index == next {
+            self.graph.add_node(id);
+        }
+
+        Ok(index)
+    }
+
+    fn statement(&mut self) -> Result<(), ParseError> {
+        if self.keyword("graph") || self.keyword("node") || self.keyword("edge") {
+            self.attributes()?;
+            return Ok(());
+        }
+
+        if self.keyword("subgraph") || self.peek() == Some(&Token::LeftBrace) {
+            return Err(self.error("subgraphs are not supported"));
+        }
+
+        if let (Some(Token::Id(_)), Some((Token::Equals, _, _))) =
+            (self.peek(), self.tokens.get(self.position + 1))
+        {
+            self.position += 2;
+            self.id()?;
+            return Ok(());
+        }
+
+        let mut chain = vec![self.node()?];
+        while let Some(&Token::Edge(directed)) = self.peek() {
+            if directed != self.graph.is_directed() {
+                return Err(self.error(match directed {
+                    true => "'->' used in undirected graph",
+                    false => "'--' used in directed graph",
+                }));
+            }
+            self.position += 1;
+            chain.push(self.node()?);
+        }
+
+        let mut attributes = self.attributes()?;
+        match chain.len() {
+            1 => {
+                if let Some(label) = attributes.remove("label") {
+                    self.graph.set_node(chain[0], label);
+                }
+            }
+            _ => {
+                let label = attributes.remove("label").unwrap_or_default();
+                for pair in chain.windows(2) {
+                    self.graph.set_edge(pair[0], pair[1], label.clone());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse(mut self) -> Result<Graph<String, String, S>, ParseError> {
+        self.keyword("strict");
+        if self.keyword("digraph") {
+            self.graph = Graph::new_directed();
+        } else if !self.keyword("graph") {
+            return Err(self.error("expected 'graph' or 'digraph'"));
+        }
+
+        if let Some(Token::Id(_)) = self.peek() {
+            self.position += 1;
+        }
+        self.expect(Token::LeftBrace, "'{'")?;
+
+        while !self.eat(&Token::RightBrace) {
+            if self.peek().is_none() {
+                return Err(self.error("expected '}'"));
+            }
+            self.statement()?;
+            self.eat(&Token::Semicolon);
+        }
+
+        if self.peek().is_some() {
+            return Err(self.error("unexpected input after graph"));
+        }
+
+        Ok(self.graph)
+    }
+}
+
+impl<S> Graph<String, String, S>
+where
+    S: GraphStorage<String>,
+{
+    pub fn from_dot(input: &str) -> Result<Self, ParseError> {
+        let (tokens, end) = Lexer::new(input).tokenize()?;
+
+        Parser {
+            tokens,
+            position: 0,
+            end,
+            graph: Graph::new_undirected(),
+            ids: HashMap::new(),
+        }
+        .parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_export_directed() {
+        let mut g = Graph::<&str, u8>::new_directed();
+        g.add_node("A");
+        g.add_node("B \"quoted\"");
+        g.set_edge(0, 1, 7);
+
+        let dot = g.to_dot(|n| n.to_string(), |e| e.to_string());
+        assert_eq!(
+            dot,
+            "digraph {\n    0 [label=\"A\"];\n    1 [label=\"B \\\"quoted\\\"\"];\n    0 -> 1 [label=\"7\"];\n}\n"
+        );
+    }
+
+    #[test]
+    fn can_export_undirected() {
+        let mut g = Graph::<(), ()>::new_undirected();
+        g.add_node(());
+        g.add_node(());
+        g.set_edge(0, 1, ());
+
+        let dot = g.to_dot(|_| String::new(), |_| String::new());
+        assert!(dot.starts_with("graph {\n"));
+        assert!(dot.contains("1 -- 0 [label=\"\"];"));
+    }
+
+    #[test]
+    fn can_parse() {
+        let input = r#"
+            // comment
+            strict digraph G {
+                # preprocessor line
+                graph [rankdir=LR];
+                node [shape=box];
+                rankdir = LR
+                a [label="Node A"];
+                a -> b -> c [label=x, color=red];
+                /* block
+                   comment */
+                "c" -> a
+            }
+        "#;
+        let g = Graph::<String, String>::from_dot(input).unwrap();
+
+        assert!(g.is_directed());
+        assert_eq!(g.node_count(), 3);
+        assert_eq!(g.node(0).unwrap().value(), "Node A");
+        assert_eq!(g.node(1).unwrap().value(), "b");
+        assert_eq!(g.edge(0, 1).unwrap().value(), "x");
+        assert_eq!(g.edge(1, 2).unwrap().value(), "x");
+        assert_eq!(g.edge(2, 0).unwrap().value(), "");
+        assert_eq!(g.edge_count(), 3);
+    }
+
+    #[test]
+    fn can_round_trip() {
+        let mut g = Graph::<String, String>::new_undirected();
+        g.add_node("A".to_owned());
+        g.add_node("B\nline".to_owned());
+        g.add_node("C \\ \"D\"".to_owned());
+        g.set_edge(0, 1, "AB".to_owned());
+        g.set_edge(2, 1, "BC".to_owned());
+
+        let dot = g.to_dot(String::clone, String::clone);
+        let parsed = Graph::<String, String>::from_dot(&dot).unwrap();
+        assert_eq!(parsed, g);
+    }
+
+    #[test]
+    fn can_report_errors() {
+        let error = Graph::<String, String>::from_dot("graph {\n  a -> b\n}").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 5));
+
+        let error = Graph::<String, String>::from_dot("digraph {\n  a [label=]\n}").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 12));
+        assert_eq!(error.to_string(), "2:12: expected identifier");
+
+        let error = Graph::<String, String>::from_dot("digraph {\n  a -> b").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 9));
+
+        let error = Graph::<String, String>::from_dot("digraph { \"a }").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 11));
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphError {
+    NodeOutOfBounds { index: usize, node_count: usize },
+    EdgeNotFound { a: usize, b: usize },
+    DirectedGraph,
+    SourceIsSink { index: usize },
+}
+
+impl Display for GraphError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphError::NodeOutOfBounds { index, node_count } => write!(
+                f,
+                "node index {} is out of bounds for a graph with {} nodes",
+                index, node_count
+            ),
+            GraphError::EdgeNotFound { a, b } => write!(f, "no edge between nodes {} and {}", a, b),
+            GraphError::DirectedGraph => f.write_str("the operation requires an undirected graph"),
+            GraphError::SourceIsSink { index } => {
+                write!(f, "node {} cannot be both the source and the sink", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
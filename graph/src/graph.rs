@@ -1,90 +1,145 @@
-use crate::matrix::AdjacencyMatrix;
+use crate::error::GraphError;
+use crate::matrix::{AdjacencyMatrix, MatrixCell};
+use crate::storage::{GraphStorage, GraphStorageMut};
+use crate::unionfind::DisjointSet;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
+use std::marker::PhantomData;
 
-#[derive(Debug, Clone, PartialEq)]
-enum MatrixCell<T> {
-    Empty,
-    Edge(T),
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "GraphData<N, S>",
+        bound(
+            serialize = "N: Serialize, S: Serialize",
+            deserialize = "N: Deserialize<'de>, S: GraphStorage<E> + Deserialize<'de>"
+        )
+    )
+)]
+pub struct Graph<N, E, S = AdjacencyMatrix<MatrixCell<E>>> {
+    edges: S,
+    nodes: Vec<N>,
+    directed: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    marker: PhantomData<E>,
 }
 
-impl<T> Default for MatrixCell<T> {
-    fn default() -> Self {
-        Self::Empty
-    }
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct GraphData<N, S> {
+    edges: S,
+    nodes: Vec<N>,
+    directed: bool,
 }
 
-impl<T> MatrixCell<T> {
-    pub fn is_empty(&self) -> bool {
-        match self {
-            MatrixCell::Empty => true,
-            MatrixCell::Edge(_) => false,
+#[cfg(feature = "serde")]
+impl<N, E, S> TryFrom<GraphData<N, S>> for Graph<N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    type Error = String;
+
+    fn try_from(graph: GraphData<N, S>) -> Result<Self, Self::Error> {
+        if graph.edges.node_count() != graph.nodes.len() {
+            return Err(format!(
+                "graph has {} nodes, but its storage holds {}",
+                graph.nodes.len(),
+                graph.edges.node_count()
+            ));
         }
-    }
 
-    #[inline]
-    pub fn is_edge(&self) -> bool {
-        !self.is_empty()
-    }
+        if graph.edges.is_symmetric() != (!graph.directed && S::new_undirected().is_symmetric()) {
+            return Err(match graph.directed {
+                true => "directed graph uses symmetric storage".to_owned(),
+                false => "undirected graph does not use symmetric storage".to_owned(),
+            });
+        }
 
-    pub fn unwrap_ref(&self) -> &T {
-        match self {
-            MatrixCell::Empty => panic!(),
-            MatrixCell::Edge(e) => e,
+        if !graph.directed && graph.edges.iter().any(|(a, b, _)| a < b) {
+            return Err("undirected graph stores an edge in the wrong direction".to_owned());
         }
-    }
-}
 
-#[derive(Clone, PartialEq)]
-pub struct Graph<N, E> {
-    edges: AdjacencyMatrix<MatrixCell<E>>,
-    nodes: Vec<N>,
-    directed: bool,
+        Ok(Self {
+            edges: graph.edges,
+            nodes: graph.nodes,
+            directed: graph.directed,
+            marker: PhantomData,
+        })
+    }
 }
 
-impl<N, E> Graph<N, E> {
+impl<N, E, S> Graph<N, E, S>
+where
+    S: GraphStorage<E>,
+{
     pub fn new_directed() -> Self {
         Self {
-            edges: AdjacencyMatrix::new(),
+            edges: S::default(),
             nodes: Vec::new(),
             directed: true,
+            marker: PhantomData,
         }
     }
 
     pub fn new_undirected() -> Self {
         Self {
-            edges: AdjacencyMatrix::new(),
+            edges: S::new_undirected(),
             nodes: Vec::new(),
             directed: false,
+            marker: PhantomData,
         }
     }
 
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.edges
+    }
+
+    pub(crate) fn into_parts(self) -> (S, Vec<N>, bool) {
+        (self.edges, self.nodes, self.directed)
+    }
+
     pub fn node_count(&self) -> usize {
         self.nodes.len()
     }
 
     pub fn edge_count(&self) -> usize {
-        let count = self
-            .edges
-            .iter()
-            .filter(|(_, _, e)| match e {
-                MatrixCell::Empty => false,
-                MatrixCell::Edge(_) => true,
-            })
-            .count();
-
-        count
+        self.edges.iter().count()
     }
 
     pub fn set_node(&mut self, index: usize, value: N) {
         self.nodes[index] = value;
     }
 
+    pub fn try_set_node(&mut self, index: usize, value: N) -> Result<(), GraphError> {
+        self.check_node(index)?;
+        self.nodes[index] = value;
+
+        Ok(())
+    }
+
+    fn check_node(&self, index: usize) -> Result<(), GraphError> {
+        match index < self.node_count() {
+            true => Ok(()),
+            false => Err(GraphError::NodeOutOfBounds {
+                index,
+                node_count: self.node_count(),
+            }),
+        }
+    }
+
     pub fn add_node(&mut self, value: N) {
         self.edges.push_default();
         self.nodes.push(value);
     }
 
-    pub fn node(&self, index: usize) -> Option<NodeRef<N, E>> {
+    pub fn node(&self, index: usize) -> Option<NodeRef<'_, N, E, S>> {
         match self.node_count() > index {
             true => Some(NodeRef {
                 graph: self,
@@ -100,6 +155,13 @@ impl<N, E> Graph<N, E> {
         self.nodes.remove(index);
     }
 
+    pub fn try_remove_node(&mut self, index: usize) -> Result<N, GraphError> {
+        self.check_node(index)?;
+        self.edges.remove(index);
+
+        Ok(self.nodes.remove(index))
+    }
+
     pub fn set_edge(&mut self, index_a: usize, index_b: usize, value: E) {
         let (a, b) = if !self.directed && index_a < index_b {
             (index_b, index_a)
@@ -107,7 +169,20 @@ impl<N, E> Graph<N, E> {
             (index_a, index_b)
         };
 
-        self.edges.set(a, b, MatrixCell::Edge(value));
+        self.edges.set(a, b, value);
+    }
+
+    pub fn try_set_edge(
+        &mut self,
+        index_a: usize,
+        index_b: usize,
+        value: E,
+    ) -> Result<(), GraphError> {
+        self.check_node(index_a)?;
+        self.check_node(index_b)?;
+        self.set_edge(index_a, index_b, value);
+
+        Ok(())
     }
 
     pub fn remove_edge(&mut self, index_a: usize, index_b: usize) {
@@ -117,10 +192,26 @@ impl<N, E> Graph<N, E> {
             (index_a, index_b)
         };
 
-        self.edges.set(a, b, MatrixCell::Empty);
+        self.edges.unset(a, b);
+    }
+
+    pub fn try_remove_edge(&mut self, index_a: usize, index_b: usize) -> Result<E, GraphError> {
+        self.check_node(index_a)?;
+        self.check_node(index_b)?;
+
+        let (a, b) = if !self.directed && index_a < index_b {
+            (index_b, index_a)
+        } else {
+            (index_a, index_b)
+        };
+
+        self.edges.unset(a, b).ok_or(GraphError::EdgeNotFound {
+            a: index_a,
+            b: index_b,
+        })
     }
 
-    pub fn edge(&self, index_a: usize, index_b: usize) -> Option<EdgeRef<N, E>> {
+    pub fn edge(&self, index_a: usize, index_b: usize) -> Option<EdgeRef<'_, N, E, S>> {
         let (a, b) = if !self.directed && index_a < index_b {
             (index_b, index_a)
         } else {
@@ -128,33 +219,97 @@ impl<N, E> Graph<N, E> {
         };
 
         if self.node_count() > a && self.node_count() > b {
-            match self.edges.get(a, b) {
-                MatrixCell::Empty => None,
-                MatrixCell::Edge(e) => Some(EdgeRef {
-                    graph: self,
-                    value: e,
-                    index_a: a,
-                    index_b: b,
-                }),
-            }
+            self.edges.get(a, b).map(|e| EdgeRef {
+                graph: self,
+                value: e,
+                index_a: a,
+                index_b: b,
+            })
         } else {
             None
         }
     }
 
-    pub fn nodes(&self) -> NodeIterator<N, E> {
+    pub fn nodes(&self) -> NodeIterator<'_, N, E, S> {
         NodeIterator::new(self)
     }
 
-    pub fn edges(&self) -> EdgeIterator<N, E> {
+    pub fn edges(&self) -> EdgeIterator<'_, N, E, S> {
         EdgeIterator::new(self)
     }
+
+    pub(crate) fn outgoing(&self, index: usize) -> Box<dyn Iterator<Item = (usize, &E)> + '_> {
+        match self.directed {
+            true => Box::new(self.edges.iter_outgoing(index).map(|(_, b, e)| (b, e))),
+            false => Box::new(self.edges.iter_adjacent(index).map(move |(a, b, e)| {
+                if a == index {
+                    (b, e)
+                } else {
+                    (a, e)
+                }
+            })),
+        }
+    }
+
+    pub(crate) fn incoming(&self, index: usize) -> Box<dyn Iterator<Item = (usize, &E)> + '_> {
+        match self.directed {
+            true => Box::new(self.edges.iter_incoming(index).map(|(a, _, e)| (a, e))),
+            false => self.outgoing(index),
+        }
+    }
+
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut sets = DisjointSet::new(self.node_count());
+        self.edges.iter().for_each(|(a, b, _)| {
+            sets.union(a, b);
+        });
+
+        sets.into_sets()
+    }
+
+    pub fn node_mut(&mut self, index: usize) -> Option<NodeRefMut<'_, N>> {
+        self.nodes
+            .get_mut(index)
+            .map(|value| NodeRefMut { value, index })
+    }
+
+    pub fn nodes_mut(&mut self) -> NodeIteratorMut<'_, N> {
+        NodeIteratorMut::new(&mut self.nodes)
+    }
 }
 
-impl<N, E> Debug for Graph<N, E>
+impl<N, E, S> Graph<N, E, S>
+where
+    S: GraphStorageMut<E>,
+{
+    pub fn edge_mut(&mut self, index_a: usize, index_b: usize) -> Option<EdgeRefMut<'_, E>> {
+        let (a, b) = if !self.directed && index_a < index_b {
+            (index_b, index_a)
+        } else {
+            (index_a, index_b)
+        };
+
+        if self.node_count() > a && self.node_count() > b {
+            self.edges.get_mut(a, b).map(|value| EdgeRefMut {
+                value,
+                index_a: a,
+                index_b: b,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn edges_mut(&mut self) -> EdgeIteratorMut<'_, E> {
+        EdgeIteratorMut::new(&mut self.edges)
+    }
+}
+
+impl<N, E, S> Debug for Graph<N, E, S>
 where
     N: Debug,
     E: Debug,
+    S: GraphStorage<E>,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str("Nodes:")?;
@@ -172,15 +327,18 @@ where
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct NodeRef<'a, N, E> {
-    graph: &'a Graph<N, E>,
+#[derive(PartialEq)]
+pub struct NodeRef<'a, N, E, S = AdjacencyMatrix<MatrixCell<E>>> {
+    graph: &'a Graph<N, E, S>,
     value: &'a N,
     index: usize,
 }
 
-impl<'a, N, E> NodeRef<'a, N, E> {
-    pub fn value(&self) -> &N {
+impl<'a, N, E, S> NodeRef<'a, N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    pub fn value(&self) -> &'a N {
         self.value
     }
 
@@ -188,47 +346,202 @@ impl<'a, N, E> NodeRef<'a, N, E> {
         self.index
     }
 
-    pub fn iter_edges(&'a self) -> AdjEdgeIterator<'a, N, E> {
+    pub fn graph(&self) -> &'a Graph<N, E, S> {
+        self.graph
+    }
+
+    pub fn iter_edges(&self) -> AdjEdgeIterator<'a, N, E, S> {
         AdjEdgeIterator::new(self.graph, self)
     }
+
+    pub fn neighbors(&self) -> NeighborIterator<'a, N, E, S> {
+        NeighborIterator::new(self.graph, self.index)
+    }
+
+    pub fn out_edges(&self) -> AdjEdgeIterator<'a, N, E, S> {
+        match self.graph.directed {
+            true => AdjEdgeIterator::from_storage(
+                self.graph,
+                self.graph.edges.iter_outgoing(self.index),
+            ),
+            false => self.iter_edges(),
+        }
+    }
+
+    pub fn in_edges(&self) -> AdjEdgeIterator<'a, N, E, S> {
+        match self.graph.directed {
+            true => AdjEdgeIterator::from_storage(
+                self.graph,
+                self.graph.edges.iter_incoming(self.index),
+            ),
+            false => self.iter_edges(),
+        }
+    }
+
+    pub fn successors(&self) -> NeighborIterator<'a, N, E, S> {
+        NeighborIterator::from_indices(self.graph, self.graph.outgoing(self.index).map(|(b, _)| b))
+    }
+
+    pub fn predecessors(&self) -> NeighborIterator<'a, N, E, S> {
+        NeighborIterator::from_indices(self.graph, self.graph.incoming(self.index).map(|(a, _)| a))
+    }
+
+    pub fn out_degree(&self) -> usize {
+        self.graph.outgoing(self.index).count()
+    }
+
+    pub fn in_degree(&self) -> usize {
+        self.graph.incoming(self.index).count()
+    }
+
+    pub fn degree(&self) -> usize {
+        self.graph.edges.iter_adjacent(self.index).count()
+    }
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct EdgeRef<'a, N, E> {
-    graph: &'a Graph<N, E>,
+impl<'a, N, E, S> Clone for NodeRef<'a, N, E, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, N, E, S> Copy for NodeRef<'a, N, E, S> {}
+
+impl<'a, N, E, S> Debug for NodeRef<'a, N, E, S>
+where
+    N: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NodeRef")
+            .field("index", &self.index)
+            .field("value", self.value)
+            .finish()
+    }
+}
+
+#[derive(PartialEq)]
+pub struct EdgeRef<'a, N, E, S = AdjacencyMatrix<MatrixCell<E>>> {
+    graph: &'a Graph<N, E, S>,
     value: &'a E,
     index_a: usize,
     index_b: usize,
 }
 
-impl<'a, N, E> EdgeRef<'a, N, E> {
-    pub fn value(&self) -> &E {
+impl<'a, N, E, S> EdgeRef<'a, N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    pub fn value(&self) -> &'a E {
         self.value
     }
 
-    pub fn a(&self) -> NodeRef<'a, N, E> {
+    pub fn index_a(&self) -> usize {
+        self.index_a
+    }
+
+    pub fn index_b(&self) -> usize {
+        self.index_b
+    }
+
+    pub fn a(&self) -> NodeRef<'a, N, E, S> {
         self.graph.node(self.index_a).unwrap()
     }
 
-    pub fn b(&self) -> NodeRef<'a, N, E> {
+    pub fn b(&self) -> NodeRef<'a, N, E, S> {
         self.graph.node(self.index_b).unwrap()
     }
 }
 
-#[derive(Debug)]
-pub struct NodeIterator<'a, N, E> {
-    graph: &'a Graph<N, E>,
+impl<'a, N, E, S> Clone for EdgeRef<'a, N, E, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, N, E, S> Copy for EdgeRef<'a, N, E, S> {}
+
+impl<'a, N, E, S> Debug for EdgeRef<'a, N, E, S>
+where
+    E: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EdgeRef")
+            .field("index_a", &self.index_a)
+            .field("index_b", &self.index_b)
+            .field("value", self.value)
+            .finish()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct NodeRefMut<'a, N> {
+    value: &'a mut N,
+    index: usize,
+}
+
+impl<'a, N> NodeRefMut<'a, N> {
+    pub fn value(&self) -> &N {
+        self.value
+    }
+
+    pub fn value_mut(&mut self) -> &mut N {
+        self.value
+    }
+
+    pub fn into_value_mut(self) -> &'a mut N {
+        self.value
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct EdgeRefMut<'a, E> {
+    value: &'a mut E,
+    index_a: usize,
+    index_b: usize,
+}
+
+impl<'a, E> EdgeRefMut<'a, E> {
+    pub fn value(&self) -> &E {
+        self.value
+    }
+
+    pub fn value_mut(&mut self) -> &mut E {
+        self.value
+    }
+
+    pub fn into_value_mut(self) -> &'a mut E {
+        self.value
+    }
+
+    pub fn index_a(&self) -> usize {
+        self.index_a
+    }
+
+    pub fn index_b(&self) -> usize {
+        self.index_b
+    }
+}
+
+pub struct NodeIterator<'a, N, E, S = AdjacencyMatrix<MatrixCell<E>>> {
+    graph: &'a Graph<N, E, S>,
     current: usize,
 }
 
-impl<'a, N, E> NodeIterator<'a, N, E> {
-    pub fn new(graph: &'a Graph<N, E>) -> Self {
+impl<'a, N, E, S> NodeIterator<'a, N, E, S> {
+    pub fn new(graph: &'a Graph<N, E, S>) -> Self {
         Self { graph, current: 0 }
     }
 }
 
-impl<'a, N, E> Iterator for NodeIterator<'a, N, E> {
-    type Item = NodeRef<'a, N, E>;
+impl<'a, N, E, S> Iterator for NodeIterator<'a, N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    type Item = NodeRef<'a, N, E, S>;
 
     fn next(&mut self) -> Option<Self::Item> {
         let item = self.graph.node(self.current);
@@ -238,19 +551,21 @@ impl<'a, N, E> Iterator for NodeIterator<'a, N, E> {
     }
 }
 
-pub struct EdgeIterator<'a, N, E> {
-    inner: Box<(dyn Iterator<Item = EdgeRef<'a, N, E>> + 'a)>,
+pub struct EdgeIterator<'a, N, E, S = AdjacencyMatrix<MatrixCell<E>>> {
+    inner: Box<dyn Iterator<Item = EdgeRef<'a, N, E, S>> + 'a>,
 }
 
-impl<'a, N, E> EdgeIterator<'a, N, E> {
-    pub fn new(graph: &'a Graph<N, E>) -> Self {
+impl<'a, N, E, S> EdgeIterator<'a, N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    pub fn new(graph: &'a Graph<N, E, S>) -> Self {
         let iter = graph
             .edges
             .iter()
-            .filter(|(_, _, cell)| cell.is_edge())
-            .map(|(index_a, index_b, value)| EdgeRef {
+            .map(move |(index_a, index_b, value)| EdgeRef {
                 graph,
-                value: value.unwrap_ref(),
+                value,
                 index_a,
                 index_b,
             });
@@ -261,28 +576,49 @@ impl<'a, N, E> EdgeIterator<'a, N, E> {
     }
 }
 
-impl<'a, N, E> Iterator for EdgeIterator<'a, N, E> {
-    type Item = EdgeRef<'a, N, E>;
+impl<'a, N, E, S> Iterator for EdgeIterator<'a, N, E, S> {
+    type Item = EdgeRef<'a, N, E, S>;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next()
     }
 }
 
-pub struct AdjEdgeIterator<'a, N, E> {
-    inner: Box<(dyn Iterator<Item = EdgeRef<'a, N, E>> + 'a)>,
+pub struct NodeIteratorMut<'a, N> {
+    inner: std::iter::Enumerate<std::slice::IterMut<'a, N>>,
 }
 
-impl<'a, N, E> AdjEdgeIterator<'a, N, E> {
-    pub fn new(graph: &'a Graph<N, E>, node: &'a NodeRef<N, E>) -> Self {
-        let iter = graph
-            .edges
-            .iter()
-            .filter(|(_, _, cell)| cell.is_edge())
-            .filter(|(x, y, _)| *x == node.index || *y == node.index)
-            .map(|(index_a, index_b, value)| EdgeRef {
-                graph,
-                value: value.unwrap_ref(),
+impl<'a, N> NodeIteratorMut<'a, N> {
+    pub fn new(nodes: &'a mut [N]) -> Self {
+        Self {
+            inner: nodes.iter_mut().enumerate(),
+        }
+    }
+}
+
+impl<'a, N> Iterator for NodeIteratorMut<'a, N> {
+    type Item = NodeRefMut<'a, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|(index, value)| NodeRefMut { value, index })
+    }
+}
+
+pub struct EdgeIteratorMut<'a, E> {
+    inner: Box<dyn Iterator<Item = EdgeRefMut<'a, E>> + 'a>,
+}
+
+impl<'a, E> EdgeIteratorMut<'a, E> {
+    pub fn new<S>(storage: &'a mut S) -> Self
+    where
+        S: GraphStorageMut<E>,
+    {
+        let iter = storage
+            .iter_mut()
+            .map(|(index_a, index_b, value)| EdgeRefMut {
+                value,
                 index_a,
                 index_b,
             });
@@ -293,8 +629,83 @@ impl<'a, N, E> AdjEdgeIterator<'a, N, E> {
     }
 }
 
-impl<'a, N, E> Iterator for AdjEdgeIterator<'a, N, E> {
-    type Item = EdgeRef<'a, N, E>;
+impl<'a, E> Iterator for EdgeIteratorMut<'a, E> {
+    type Item = EdgeRefMut<'a, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+pub struct AdjEdgeIterator<'a, N, E, S = AdjacencyMatrix<MatrixCell<E>>> {
+    inner: Box<dyn Iterator<Item = EdgeRef<'a, N, E, S>> + 'a>,
+}
+
+impl<'a, N, E, S> AdjEdgeIterator<'a, N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    pub fn new(graph: &'a Graph<N, E, S>, node: &NodeRef<N, E, S>) -> Self {
+        Self::from_storage(graph, graph.edges.iter_adjacent(node.index))
+    }
+
+    fn from_storage(
+        graph: &'a Graph<N, E, S>,
+        edges: Box<dyn Iterator<Item = (usize, usize, &'a E)> + 'a>,
+    ) -> Self {
+        let iter = edges.map(move |(index_a, index_b, value)| EdgeRef {
+            graph,
+            value,
+            index_a,
+            index_b,
+        });
+
+        Self {
+            inner: Box::new(iter),
+        }
+    }
+}
+
+impl<'a, N, E, S> Iterator for AdjEdgeIterator<'a, N, E, S> {
+    type Item = EdgeRef<'a, N, E, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+pub struct NeighborIterator<'a, N, E, S = AdjacencyMatrix<MatrixCell<E>>> {
+    inner: Box<dyn Iterator<Item = NodeRef<'a, N, E, S>> + 'a>,
+}
+
+impl<'a, N, E, S> NeighborIterator<'a, N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    pub fn new(graph: &'a Graph<N, E, S>, index: usize) -> Self {
+        let others = graph
+            .edges
+            .iter_adjacent(index)
+            .map(move |(a, b, _)| if a == index { b } else { a });
+
+        Self::from_indices(graph, others)
+    }
+
+    fn from_indices(graph: &'a Graph<N, E, S>, indices: impl Iterator<Item = usize> + 'a) -> Self {
+        let iter = indices.map(move |other| NodeRef {
+            graph,
+            value: &graph.nodes[other],
+            index: other,
+        });
+
+        Self {
+            inner: Box::new(iter),
+        }
+    }
+}
+
+impl<'a, N, E, S> Iterator for NeighborIterator<'a, N, E, S> {
+    type Item = NodeRef<'a, N, E, S>;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next()
@@ -304,6 +715,8 @@ impl<'a, N, E> Iterator for AdjEdgeIterator<'a, N, E> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::list::AdjacencyList;
+    use crate::map::AdjacencyMap;
 
     fn directed_test_graph() -> Graph<String, String> {
         let mut g = Graph::<String, String>::new_directed();
@@ -330,6 +743,18 @@ mod tests {
         g
     }
 
+    fn map_test_graph() -> Graph<String, String, AdjacencyMap<String>> {
+        let mut g = Graph::<String, String, AdjacencyMap<String>>::new_undirected();
+        g.add_node("A".to_owned());
+        g.add_node("B".to_owned());
+        g.add_node("C".to_owned());
+
+        g.set_edge(0, 1, "AB".to_owned());
+        g.set_edge(0, 2, "AC".to_owned());
+
+        g
+    }
+
     #[test]
     fn can_create() {
         let a = Graph::<u8, u8>::new_directed();
@@ -426,7 +851,7 @@ mod tests {
     #[test]
     fn can_iter_over_nodes() {
         let g = directed_test_graph();
-        let test = vec!["A", "B", "C"];
+        let test = ["A", "B", "C"];
 
         assert_eq!(g.nodes().count(), 3);
 
@@ -441,7 +866,7 @@ mod tests {
     #[test]
     fn can_iter_over_edges() {
         let g = directed_test_graph();
-        let test = vec!["BA", "AB", "AC"];
+        let test = ["BA", "AB", "AC"];
 
         assert_eq!(g.edges().count(), 3);
 
@@ -471,4 +896,221 @@ mod tests {
             assert_eq!(iter.next(), None);
         }
     }
+
+    #[test]
+    fn can_use_other_storage() {
+        let mut g = map_test_graph();
+        assert_eq!(g.edge_count(), 2);
+        assert_eq!(g.edge(1, 0).unwrap().value(), "AB");
+        assert_eq!(g.edge(2, 0).unwrap().value(), "AC");
+        assert_eq!(g.edge(1, 2), None);
+
+        g.remove_node(0);
+        assert_eq!(g.node(0).unwrap().value(), "B");
+        assert_eq!(g.edge_count(), 0);
+
+        g.set_edge(0, 1, "BC".to_owned());
+        assert_eq!(g.edge(1, 0).unwrap().value(), "BC");
+    }
+
+    #[test]
+    fn can_iter_over_neighbors() {
+        let g = directed_test_graph();
+        let neighbors: Vec<usize> = g.node(0).unwrap().neighbors().map(|n| n.index()).collect();
+        assert_eq!(neighbors, vec![1, 1, 2]);
+
+        let g = undirected_test_graph();
+        let neighbors: Vec<usize> = g.node(0).unwrap().neighbors().map(|n| n.index()).collect();
+        assert_eq!(neighbors, vec![1, 2]);
+    }
+
+    #[test]
+    fn can_iter_over_adj_edges_of_list() {
+        let mut g = Graph::<String, String, AdjacencyList<String>>::new_directed();
+        g.add_node("A".to_owned());
+        g.add_node("B".to_owned());
+        g.add_node("C".to_owned());
+        g.set_edge(0, 1, "AB".to_owned());
+        g.set_edge(1, 0, "BA".to_owned());
+        g.set_edge(0, 2, "AC".to_owned());
+
+        let node = g.node(1).unwrap();
+        let mut iter = node.iter_edges();
+        assert_eq!(iter.next().unwrap().value(), "BA");
+        assert_eq!(iter.next().unwrap().value(), "AB");
+        assert_eq!(iter.next(), None);
+
+        let neighbors: Vec<usize> = g.node(0).unwrap().neighbors().map(|n| n.index()).collect();
+        assert_eq!(neighbors, vec![1, 2, 1]);
+    }
+
+    #[test]
+    fn can_find_connected_components() {
+        let mut g = undirected_test_graph();
+        assert_eq!(g.connected_components(), vec![vec![0, 1, 2]]);
+
+        g.add_node("D".to_owned());
+        g.add_node("E".to_owned());
+        g.set_edge(4, 1, "EB".to_owned());
+        g.remove_edge(0, 1);
+        assert_eq!(
+            g.connected_components(),
+            vec![vec![0, 2], vec![1, 4], vec![3]]
+        );
+    }
+
+    #[test]
+    fn can_mutate_nodes() {
+        let mut g = directed_test_graph();
+
+        g.node_mut(1).unwrap().value_mut().push('!');
+        assert_eq!(g.node(1).unwrap().value(), "B!");
+        assert_eq!(g.node_mut(3), None);
+
+        g.nodes_mut()
+            .for_each(|mut n| *n.value_mut() = format!("{}{}", n.value(), n.index()));
+        let values: Vec<&String> = g.nodes().map(|n| n.value()).collect();
+        assert_eq!(values, vec!["A0", "B!1", "C2"]);
+    }
+
+    #[test]
+    fn can_mutate_edges() {
+        let mut g = undirected_test_graph();
+
+        g.edge_mut(0, 1).unwrap().value_mut().push('!');
+        assert_eq!(g.edge(1, 0).unwrap().value(), "AB!");
+        assert_eq!(g.edge_mut(1, 2), None);
+
+        g.edges_mut().for_each(|mut e| {
+            let suffix = format!("{}{}", e.index_a(), e.index_b());
+            e.value_mut().push_str(&suffix);
+        });
+        assert_eq!(g.edge(0, 1).unwrap().value(), "AB!10");
+        assert_eq!(g.edge(0, 2).unwrap().value(), "AC20");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn can_serialize() {
+        let g = undirected_test_graph();
+        let json = serde_json::to_string(&g).unwrap();
+        let restored: Graph<String, String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, g);
+
+        let g = directed_test_graph();
+        let json = serde_json::to_string(&g).unwrap();
+        let restored: Graph<String, String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, g);
+
+        let mismatched =
+            r#"{"edges":{"data":["Empty"],"size":1},"nodes":["A","B"],"directed":true}"#;
+        assert!(serde_json::from_str::<Graph<String, String>>(mismatched).is_err());
+
+        let flipped = r#"{"edges":{"data":["Empty","Empty",{"Edge":"AB"},"Empty"],"size":2},"nodes":["A","B"],"directed":false}"#;
+        assert!(serde_json::from_str::<Graph<String, String>>(flipped).is_err());
+
+        let json = serde_json::to_string(&undirected_test_graph()).unwrap();
+        let tampered = json.replace(r#""directed":false"#, r#""directed":true"#);
+        assert_ne!(tampered, json);
+        assert!(serde_json::from_str::<Graph<String, String>>(&tampered).is_err());
+
+        let json = serde_json::to_string(&directed_test_graph()).unwrap();
+        let tampered = json.replace(r#""directed":true"#, r#""directed":false"#);
+        assert_ne!(tampered, json);
+        assert!(serde_json::from_str::<Graph<String, String>>(&tampered).is_err());
+    }
+
+    #[test]
+    fn can_fail_without_panicking() {
+        let mut g = directed_test_graph();
+
+        let out_of_bounds = GraphError::NodeOutOfBounds {
+            index: 3,
+            node_count: 3,
+        };
+        assert_eq!(g.try_set_node(3, "D".to_owned()), Err(out_of_bounds));
+        assert_eq!(g.try_set_edge(0, 3, "AD".to_owned()), Err(out_of_bounds));
+        assert_eq!(g.try_remove_edge(3, 0), Err(out_of_bounds));
+        assert_eq!(g.try_remove_node(3), Err(out_of_bounds));
+        assert_eq!(
+            g.try_remove_edge(2, 0),
+            Err(GraphError::EdgeNotFound { a: 2, b: 0 })
+        );
+
+        assert_eq!(g.try_set_node(2, "C!".to_owned()), Ok(()));
+        assert_eq!(g.try_set_edge(2, 0, "CA".to_owned()), Ok(()));
+        assert_eq!(g.try_remove_edge(0, 1), Ok("AB".to_owned()));
+        assert_eq!(g.try_remove_node(0), Ok("A".to_owned()));
+        assert_eq!(g.node(1).unwrap().value(), "C!");
+        assert_eq!(g.edge_count(), 0);
+        assert_eq!(
+            out_of_bounds.to_string(),
+            "node index 3 is out of bounds for a graph with 3 nodes"
+        );
+    }
+
+    #[test]
+    fn can_tell_in_and_out_edges_apart() {
+        let g = directed_test_graph();
+        let a = g.node(0).unwrap();
+
+        let out: Vec<&String> = a.out_edges().map(|e| e.value()).collect();
+        assert_eq!(out, vec!["AB", "AC"]);
+        let incoming: Vec<&String> = a.in_edges().map(|e| e.value()).collect();
+        assert_eq!(incoming, vec!["BA"]);
+
+        let successors: Vec<usize> = a.successors().map(|n| n.index()).collect();
+        assert_eq!(successors, vec![1, 2]);
+        let predecessors: Vec<usize> = g
+            .node(2)
+            .unwrap()
+            .predecessors()
+            .map(|n| n.index())
+            .collect();
+        assert_eq!(predecessors, vec![0]);
+
+        assert_eq!(a.out_degree(), 2);
+        assert_eq!(a.in_degree(), 1);
+        assert_eq!(a.degree(), 3);
+        assert_eq!(g.node(2).unwrap().out_degree(), 0);
+
+        let g = undirected_test_graph();
+        let a = g.node(0).unwrap();
+        assert_eq!(a.out_degree(), 2);
+        assert_eq!(a.in_degree(), 2);
+        assert_eq!(a.degree(), 2);
+        let predecessors: Vec<usize> = a.predecessors().map(|n| n.index()).collect();
+        assert_eq!(predecessors, vec![1, 2]);
+    }
+
+    #[test]
+    fn can_tell_in_and_out_edges_apart_in_list() {
+        let mut g = Graph::<(), u8, AdjacencyList<u8>>::new_directed();
+        for _ in 0..3 {
+            g.add_node(());
+        }
+        g.set_edge(0, 1, 1);
+        g.set_edge(2, 0, 20);
+        g.set_edge(0, 0, 0);
+
+        let a = g.node(0).unwrap();
+        let out: Vec<u8> = a.out_edges().map(|e| *e.value()).collect();
+        assert_eq!(out, vec![1, 0]);
+        let incoming: Vec<u8> = a.in_edges().map(|e| *e.value()).collect();
+        assert_eq!(incoming, vec![20, 0]);
+        assert_eq!(a.degree(), 3);
+    }
+
+    #[test]
+    fn can_store_undirected_in_triangle() {
+        let g = undirected_test_graph();
+        assert!(g.edges.is_triangular());
+        assert!(!directed_test_graph().edges.is_triangular());
+
+        let mut g = undirected_test_graph();
+        g.set_edge(1, 1, "BB".to_owned());
+        g.remove_node(0);
+        assert_eq!(g.edge(0, 0).unwrap().value(), "BB");
+        assert_eq!(g.edge_count(), 1);
+    }
 }
@@ -0,0 +1,184 @@
+use super::Attribute;
+use crate::error::ParseError;
+use crate::graph::Graph;
+use crate::storage::GraphStorage;
+use std::collections::{HashMap, HashSet};
+use std::io::{Error, ErrorKind, Write};
+
+fn tokens(line: &str) -> Vec<(usize, usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (column, (offset, c)) in line.char_indices().enumerate() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some((column + 1, offset)),
+            (true, Some((column, begin))) => {
+                tokens.push((column, begin, &line[begin..offset]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some((column, begin)) = start {
+        tokens.push((column, begin, &line[begin..]));
+    }
+
+    tokens
+}
+
+pub fn read_edge_list<N, E, S>(input: &str, directed: bool) -> Result<Graph<N, E, S>, ParseError>
+where
+    N: Attribute,
+    E: Attribute,
+    S: GraphStorage<E>,
+{
+    let mut graph = match directed {
+        true => Graph::new_directed(),
+        false => Graph::new_undirected(),
+    };
+    let mut ids: HashMap<String, usize> = HashMap::new();
+
+    for (number, line) in input.lines().enumerate() {
+        let line = match line.find('#') {
+            Some(comment) => &line[..comment],
+            None => line,
+        };
+
+        let tokens = tokens(line);
+        let mut nodes = Vec::with_capacity(2);
+        for &(column, _, token) in tokens.iter().take(2) {
+            let index = match ids.get(token) {
+                Some(&index) => index,
+                None => {
+                    let value = N::from_attribute(token)
+                        .map_err(|message| ParseError::new(number + 1, column, message))?;
+                    graph.add_node(value);
+                    ids.insert(token.to_owned(), graph.node_count() - 1);
+                    graph.node_count() - 1
+                }
+            };
+            nodes.push(index);
+        }
+
+        if let [a, b] = nodes[..] {
+            let (column, data) = match tokens.get(2) {
+                Some(&(column, offset, _)) => (column, line[offset..].trim_end()),
+                None => (line.chars().count() + 1, ""),
+            };
+            let value = E::from_attribute(data)
+                .map_err(|message| ParseError::new(number + 1, column, message))?;
+            graph.set_edge(a, b, value);
+        }
+    }
+
+    Ok(graph)
+}
+
+pub fn write_edge_list<N, E, S, W>(graph: &Graph<N, E, S>, mut writer: W) -> std::io::Result<()>
+where
+    N: Attribute,
+    E: Attribute,
+    S: GraphStorage<E>,
+    W: Write,
+{
+    let mut ids = Vec::with_capacity(graph.node_count());
+    let mut seen = HashSet::new();
+    for n in graph.nodes() {
+        let id = n.value().to_attribute();
+        if id.is_empty() || id.contains(|c: char| c.is_whitespace() || c == '#') {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("node {} cannot be written as '{}'", n.index(), id),
+            ));
+        }
+        if !seen.insert(id.clone()) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("node {} duplicates id '{}'", n.index(), id),
+            ));
+        }
+        ids.push(id);
+    }
+
+    let mut connected = vec![false; graph.node_count()];
+    for e in graph.edges() {
+        connected[e.index_a()] = true;
+        connected[e.index_b()] = true;
+        write!(writer, "{} {}", ids[e.index_a()], ids[e.index_b()])?;
+        if E::KEY.is_some() {
+            write!(writer, " {}", e.value().to_attribute())?;
+        }
+        writeln!(writer)?;
+    }
+
+    for (index, id) in ids.iter().enumerate() {
+        if !connected[index] {
+            writeln!(writer, "{}", id)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_read_edge_list() {
+        let input = "# weighted\na b 1.5\nb c 2 # trailing\n\nd\n c   a   4\n";
+        let g: Graph<String, f64> = read_edge_list(input, true).unwrap();
+
+        assert_eq!(g.node_count(), 4);
+        let names: Vec<&str> = g.nodes().map(|n| n.value().as_str()).collect();
+        assert_eq!(names, vec!["a", "b", "c", "d"]);
+        assert_eq!(g.edge(0, 1).unwrap().value(), &1.5);
+        assert_eq!(g.edge(1, 2).unwrap().value(), &2.0);
+        assert_eq!(g.edge(2, 0).unwrap().value(), &4.0);
+        assert_eq!(g.edge_count(), 3);
+    }
+
+    #[test]
+    fn can_report_edge_list_errors() {
+        let error =
+            read_edge_list::<String, f64, crate::AdjacencyList<f64>>("a b 1\n  b  c x\n", true)
+                .unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 8));
+
+        let error =
+            read_edge_list::<u32, (), crate::AdjacencyList<()>>("1 2\n3 y\n", false).unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 3));
+    }
+
+    #[test]
+    fn can_round_trip_edge_list() {
+        let mut g = Graph::<u32, ()>::new_undirected();
+        for id in [10, 20, 30, 40] {
+            g.add_node(id);
+        }
+        g.set_edge(0, 1, ());
+        g.set_edge(2, 1, ());
+
+        let mut output = Vec::new();
+        write_edge_list(&g, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output, "20 10\n30 20\n40\n");
+
+        let parsed: Graph<u32, ()> = read_edge_list(&output, false).unwrap();
+        assert_eq!(parsed.node_count(), 4);
+        assert_eq!(parsed.edge_count(), 2);
+        assert!(parsed.edge(0, 1).is_some());
+        assert!(parsed.edge(0, 2).is_some());
+    }
+
+    #[test]
+    fn can_reject_invalid_ids() {
+        let mut g = Graph::<String, ()>::new_directed();
+        g.add_node("a b".to_owned());
+        assert!(write_edge_list(&g, Vec::new()).is_err());
+
+        let mut g = Graph::<String, ()>::new_directed();
+        g.add_node("a".to_owned());
+        g.add_node("a".to_owned());
+        assert!(write_edge_list(&g, Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,614 @@
+use super::Attribute;
+use crate::error::ParseError;
+use crate::graph::Graph;
+use crate::storage::GraphStorage;
+use std::collections::HashMap;
+use std::io::Write;
+
+enum XmlEvent {
+    Start {
+        name: String,
+        attributes: Vec<(String, String)>,
+        empty: bool,
+    },
+    End(String),
+    Text(String),
+}
+
+struct XmlReader<'a> {
+    input: &'a str,
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> XmlReader<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.offset..]
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, self.column, message)
+    }
+
+    fn advance(&mut self, bytes: usize) {
+        for c in self.input[self.offset..self.offset + bytes].chars() {
+            match c {
+                '\n' => {
+                    self.line += 1;
+                    self.column = 1;
+                }
+                _ => self.column += 1,
+            }
+        }
+        self.offset += bytes;
+    }
+
+    fn skip_past(&mut self, terminator: &str, what: &str) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        match rest.find(terminator) {
+            Some(end) => {
+                self.advance(end + terminator.len());
+                Ok(&rest[..end])
+            }
+            None => Err(self.error(format!("unterminated {}", what))),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.advance(self.rest().len() - trimmed.len());
+    }
+
+    fn name(&mut self) -> Result<String, ParseError> {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '='))
+            .unwrap_or(rest.len());
+        if end == 0 {
+            return Err(self.error("expected name"));
+        }
+        self.advance(end);
+
+        Ok(rest[..end].to_owned())
+    }
+
+    fn unescape(&self, text: &str) -> Result<String, ParseError> {
+        let mut value = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find('&') {
+            value.push_str(&rest[..start]);
+            let end = rest[start..]
+                .find(';')
+                .ok_or_else(|| self.error("unterminated entity"))?;
+            let entity = &rest[start + 1..start + end];
+            let c = match entity {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                _ => entity
+                    .strip_prefix("#x")
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(|dec| dec.parse()))
+                    .and_then(|code| code.ok())
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| self.error(format!("unknown entity '&{};'", entity)))?,
+            };
+            value.push(c);
+            rest = &rest[start + end + 1..];
+        }
+        value.push_str(rest);
+
+        Ok(value)
+    }
+
+    fn next(&mut self) -> Result<Option<(XmlEvent, usize, usize)>, ParseError> {
+        loop {
+            let (line, column) = (self.line, self.column);
+            let rest = self.rest();
+
+            if rest.is_empty() {
+                return Ok(None);
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->", "comment")?;
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>", "processing instruction")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.advance(9);
+                let text = self.skip_past("]]>", "CDATA section")?;
+                return Ok(Some((XmlEvent::Text(text.to_owned()), line, column)));
+            } else if rest.starts_with("<!") {
+                self.skip_past(">", "declaration")?;
+            } else if rest.starts_with("</") {
+                self.advance(2);
+                let name = self.name()?;
+                self.skip_whitespace();
+                if !self.rest().starts_with('>') {
+                    return Err(self.error("expected '>'"));
+                }
+                self.advance(1);
+                return Ok(Some((XmlEvent::End(name), line, column)));
+            } else if rest.starts_with('<') {
+                self.advance(1);
+                let name = self.name()?;
+                let mut attributes = Vec::new();
+                let empty = loop {
+                    self.skip_whitespace();
+                    let rest = self.rest();
+                    if rest.starts_with("/>") {
+                        self.advance(2);
+                        break true;
+                    } else if rest.starts_with('>') {
+                        self.advance(1);
+                        break false;
+                    } else if rest.is_empty() {
+                        return Err(self.error("unterminated tag"));
+                    }
+
+                    let key = self.name()?;
+                    self.skip_whitespace();
+                    if !self.rest().starts_with('=') {
+                        return Err(self.error("expected '='"));
+                    }
+                    self.advance(1);
+                    self.skip_whitespace();
+
+                    let quote = match self.rest().chars().next() {
+                        Some(quote @ ('"' | '\'')) => quote,
+                        _ => return Err(self.error("expected quoted value")),
+                    };
+                    self.advance(1);
+                    let raw = self.skip_past(&quote.to_string(), "attribute value")?;
+                    attributes.push((key, self.unescape(raw)?));
+                };
+
+                return Ok(Some((
+                    XmlEvent::Start {
+                        name,
+                        attributes,
+                        empty,
+                    },
+                    line,
+                    column,
+                )));
+            } else {
+                let end = rest.find('<').unwrap_or(rest.len());
+                self.advance(end);
+                let text = self.unescape(&rest[..end])?;
+                return Ok(Some((XmlEvent::Text(text), line, column)));
+            }
+        }
+    }
+}
+
+struct Key {
+    domain: String,
+    name: String,
+    default: Option<String>,
+}
+
+struct Element {
+    line: usize,
+    column: usize,
+    data: Vec<(String, String, usize, usize)>,
+}
+
+fn attribute<'b>(
+    attributes: &'b [(String, String)],
+    name: &str,
+    line: usize,
+    column: usize,
+) -> Result<&'b str, ParseError> {
+    attributes
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+        .ok_or_else(|| ParseError::new(line, column, format!("missing attribute '{}'", name)))
+}
+
+fn payload<T>(element: &Element, keys: &HashMap<String, Key>, domain: &str) -> Result<T, ParseError>
+where
+    T: Attribute,
+{
+    let name = match T::KEY {
+        Some(name) => name,
+        None => {
+            return T::from_attribute("")
+                .map_err(|m| ParseError::new(element.line, element.column, m))
+        }
+    };
+    let matches = |key: &Key| key.name == name && (key.domain == domain || key.domain == "all");
+
+    let data = element
+        .data
+        .iter()
+        .find(|(key, _, _, _)| keys.get(key).is_some_and(matches));
+    if let Some((_, value, line, column)) = data {
+        return T::from_attribute(value).map_err(|m| ParseError::new(*line, *column, m));
+    }
+
+    let default = keys
+        .values()
+        .find(|key| matches(key))
+        .and_then(|key| key.default.as_deref())
+        .unwrap_or("");
+
+    T::from_attribute(default).map_err(|m| ParseError::new(element.line, element.column, m))
+}
+
+pub fn read_graphml<N, E, S>(input: &str) -> Result<Graph<N, E, S>, ParseError>
+where
+    N: Attribute,
+    E: Attribute,
+    S: GraphStorage<E>,
+{
+    let mut reader = XmlReader::new(input);
+    let mut stack: Vec<String> = Vec::new();
+    let mut keys: HashMap<String, Key> = HashMap::new();
+    let mut key = None;
+    let mut text = String::new();
+    let mut directed = None;
+    let mut nodes: Vec<(String, Element)> = Vec::new();
+    let mut edges: Vec<(String, String, Element)> = Vec::new();
+
+    while let Some((event, line, column)) = reader.next()? {
+        match event {
+            XmlEvent::Start {
+                name,
+                attributes,
+                empty,
+            } => {
+                let get = |name| attribute(&attributes, name, line, column);
+                match (stack.last().map(String::as_str), name.as_str()) {
+                    (None, "graphml") => {}
+                    (None, _) => return Err(ParseError::new(line, column, "expected <graphml>")),
+                    (Some("graphml"), "key") => {
+                        let id = get("id")?.to_owned();
+                        let domain = get("for").unwrap_or("all").to_owned();
+                        let name = get("attr.name").unwrap_or(&id).to_owned();
+                        keys.insert(
+                            id.clone(),
+                            Key {
+                                domain,
+                                name,
+                                default: None,
+                            },
+                        );
+                        key = Some(id);
+                    }
+                    (Some("graphml"), "graph") => {
+                        if directed.is_some() {
+                            return Err(ParseError::new(
+                                line,
+                                column,
+                                "multiple graphs are not supported",
+                            ));
+                        }
+                        directed = Some(get("edgedefault").unwrap_or("directed") == "directed");
+                    }
+                    (Some("graph" | "node" | "edge"), "graph") => {
+                        return Err(ParseError::new(
+                            line,
+                            column,
+                            "nested graphs are not supported",
+                        ));
+                    }
+                    (Some("graph"), "hyperedge") => {
+                        return Err(ParseError::new(
+                            line,
+                            column,
+                            "hyperedges are not supported",
+                        ));
+                    }
+                    (Some("graph"), "node") => {
+                        let id = get("id")?.to_owned();
+                        nodes.push((
+                            id,
+                            Element {
+                                line,
+                                column,
+                                data: Vec::new(),
+                            },
+                        ));
+                    }
+                    (Some("graph"), "edge") => {
+                        let source = get("source")?.to_owned();
+                        let target = get("target")?.to_owned();
+                        edges.push((
+                            source,
+                            target,
+                            Element {
+                                line,
+                                column,
+                                data: Vec::new(),
+                            },
+                        ));
+                    }
+                    (_, "graph") => {
+                        return Err(ParseError::new(
+                            line,
+                            column,
+                            "<graph> must be inside <graphml>",
+                        ));
+                    }
+                    (_, "node" | "edge") => {
+                        return Err(ParseError::new(
+                            line,
+                            column,
+                            format!("<{}> must be inside <graph>", name),
+                        ));
+                    }
+                    (Some(parent @ ("node" | "edge")), "data") => {
+                        let key = get("key")?.to_owned();
+                        let element = match parent {
+                            "node" => &mut nodes.last_mut().unwrap().1,
+                            _ => &mut edges.last_mut().unwrap().2,
+                        };
+                        element.data.push((key, String::new(), line, column));
+                        text.clear();
+                    }
+                    (Some("key"), "default") => text.clear(),
+                    _ => {}
+                }
+
+                if !empty {
+                    stack.push(name);
+                }
+            }
+            XmlEvent::End(name) => {
+                if stack.pop().as_ref() != Some(&name) {
+                    return Err(ParseError::new(
+                        line,
+                        column,
+                        format!("unexpected </{}>", name),
+                    ));
+                }
+
+                match (stack.last().map(String::as_str), name.as_str()) {
+                    (Some("node"), "data") => {
+                        nodes.last_mut().unwrap().1.data.last_mut().unwrap().1 =
+                            std::mem::take(&mut text)
+                    }
+                    (Some("edge"), "data") => {
+                        edges.last_mut().unwrap().2.data.last_mut().unwrap().1 =
+                            std::mem::take(&mut text)
+                    }
+                    (Some("key"), "default") => {
+                        if let Some(key) = key.as_ref().and_then(|id| keys.get_mut(id)) {
+                            key.default = Some(std::mem::take(&mut text));
+                        }
+                    }
+                    (Some("graphml"), "key") => key = None,
+                    _ => {}
+                }
+            }
+            XmlEvent::Text(value) => {
+                if matches!(stack.last().map(String::as_str), Some("data" | "default")) {
+                    text.push_str(&value);
+                }
+            }
+        }
+    }
+
+    if let Some(name) = stack.last() {
+        return Err(reader.error(format!("unclosed <{}>", name)));
+    }
+
+    let mut graph = match directed {
+        Some(true) => Graph::new_directed(),
+        Some(false) => Graph::new_undirected(),
+        None => return Err(reader.error("missing <graph>")),
+    };
+
+    let mut ids = HashMap::new();
+    for (id, element) in &nodes {
+        if ids.insert(id.as_str(), graph.node_count()).is_some() {
+            return Err(ParseError::new(
+                element.line,
+                element.column,
+                format!("duplicate node '{}'", id),
+            ));
+        }
+        graph.add_node(payload(element, &keys, "node")?);
+    }
+
+    for (source, target, element) in &edges {
+        let index = |id: &String| {
+            ids.get(id.as_str()).copied().ok_or_else(|| {
+                ParseError::new(
+                    element.line,
+                    element.column,
+                    format!("unknown node '{}'", id),
+                )
+            })
+        };
+        let (a, b) = (index(source)?, index(target)?);
+        graph.set_edge(a, b, payload(element, &keys, "edge")?);
+    }
+
+    Ok(graph)
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+pub fn write_graphml<N, E, S, W>(graph: &Graph<N, E, S>, mut writer: W) -> std::io::Result<()>
+where
+    N: Attribute,
+    E: Attribute,
+    S: GraphStorage<E>,
+    W: Write,
+{
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    )?;
+    if let Some(name) = N::KEY {
+        writeln!(
+            writer,
+            r#"  <key id="n_{0}" for="node" attr.name="{0}" attr.type="{1}"/>"#,
+            escape(name),
+            N::TYPE
+        )?;
+    }
+    if let Some(name) = E::KEY {
+        writeln!(
+            writer,
+            r#"  <key id="e_{0}" for="edge" attr.name="{0}" attr.type="{1}"/>"#,
+            escape(name),
+            E::TYPE
+        )?;
+    }
+
+    let edgedefault = match graph.is_directed() {
+        true => "directed",
+        false => "undirected",
+    };
+    writeln!(writer, r#"  <graph id="G" edgedefault="{}">"#, edgedefault)?;
+
+    for n in graph.nodes() {
+        match N::KEY {
+            Some(name) => writeln!(
+                writer,
+                r#"    <node id="n{}"><data key="n_{}">{}</data></node>"#,
+                n.index(),
+                escape(name),
+                escape(&n.value().to_attribute())
+            )?,
+            None => writeln!(writer, r#"    <node id="n{}"/>"#, n.index())?,
+        }
+    }
+
+    for e in graph.edges() {
+        match E::KEY {
+            Some(name) => writeln!(
+                writer,
+                r#"    <edge source="n{}" target="n{}"><data key="e_{}">{}</data></edge>"#,
+                e.index_a(),
+                e.index_b(),
+                escape(name),
+                escape(&e.value().to_attribute())
+            )?,
+            None => writeln!(
+                writer,
+                r#"    <edge source="n{}" target="n{}"/>"#,
+                e.index_a(),
+                e.index_b()
+            )?,
+        }
+    }
+
+    writeln!(writer, "  </graph>")?;
+    writeln!(writer, "</graphml>")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_read_graphml() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- exported by some tool -->
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="label" attr.type="string">
+    <default>unnamed</default>
+  </key>
+  <key id="d1" for="edge" attr.name="weight" attr.type="double"/>
+  <key id="d2" for="edge" attr.name="color" attr.type="string"/>
+  <graph id="G" edgedefault="undirected">
+    <desc>test graph</desc>
+    <node id="a"><data key="d0">A &amp; B</data></node>
+    <node id="b"/>
+    <edge source="a" target="b">
+      <data key="d2">red</data>
+      <data key="d1">2.5</data>
+    </edge>
+    <edge source="b" target="c"><data key="d1">1</data></edge>
+    <node id="c"><data key="d0"><![CDATA[<C>]]></data></node>
+  </graph>
+</graphml>
+"#;
+        let g: Graph<String, f64> = read_graphml(input).unwrap();
+
+        assert!(!g.is_directed());
+        let labels: Vec<&str> = g.nodes().map(|n| n.value().as_str()).collect();
+        assert_eq!(labels, vec!["A & B", "unnamed", "<C>"]);
+        assert_eq!(g.edge(0, 1).unwrap().value(), &2.5);
+        assert_eq!(g.edge(2, 1).unwrap().value(), &1.0);
+    }
+
+    #[test]
+    fn can_report_graphml_errors() {
+        let input =
+            "<graphml>\n  <graph>\n    <edge source=\"a\" target=\"b\"/>\n  </graph>\n</graphml>";
+        let error = read_graphml::<String, (), crate::AdjacencyList<()>>(input).unwrap_err();
+        assert_eq!((error.line(), error.column()), (3, 5));
+        assert_eq!(error.message(), "unknown node 'a'");
+
+        let input = "<graphml>\n  <graph>\n  </grph>\n</graphml>";
+        let error = read_graphml::<String, (), crate::AdjacencyList<()>>(input).unwrap_err();
+        assert_eq!((error.line(), error.column()), (3, 3));
+
+        let input = "<graphml>\n<key id=\"w\" for=\"edge\" attr.name=\"weight\"/><graph>\n<node id=\"a\"/>\n<edge source=\"a\" target=\"a\"><data key=\"w\">x</data></edge></graph></graphml>";
+        let error = read_graphml::<(), u32, crate::AdjacencyList<u32>>(input).unwrap_err();
+        assert_eq!((error.line(), error.column()), (4, 29));
+
+        let input = "<graphml><node><data key=\"x\">a</data></node></graphml>";
+        let error = read_graphml::<String, (), crate::AdjacencyList<()>>(input).unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 10));
+        assert_eq!(error.message(), "<node> must be inside <graph>");
+
+        let input = "<graphml>\n<graph>\n<graph>\n<node id=\"a\"/>\n</graph>\n</graph>\n</graphml>";
+        let error = read_graphml::<String, (), crate::AdjacencyList<()>>(input).unwrap_err();
+        assert_eq!((error.line(), error.column()), (3, 1));
+        assert_eq!(error.message(), "nested graphs are not supported");
+    }
+
+    #[test]
+    fn can_round_trip_graphml() {
+        let mut g = Graph::<String, i32>::new_directed();
+        g.add_node("<A>".to_owned());
+        g.add_node("\"B\" & 'C'".to_owned());
+        g.add_node(String::new());
+        g.set_edge(0, 1, -3);
+        g.set_edge(1, 0, 4);
+        g.set_edge(2, 2, 0);
+
+        let mut output = Vec::new();
+        write_graphml(&g, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output
+            .contains(r#"<key id="e_weight" for="edge" attr.name="weight" attr.type="int"/>"#));
+
+        let parsed: Graph<String, i32> = read_graphml(&output).unwrap();
+        assert_eq!(parsed, g);
+    }
+}
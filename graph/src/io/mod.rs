@@ -0,0 +1,67 @@
+mod edgelist;
+mod graphml;
+
+pub use edgelist::*;
+pub use graphml::*;
+
+pub trait Attribute: Sized {
+    const KEY: Option<&'static str>;
+    const TYPE: &'static str;
+
+    fn to_attribute(&self) -> String;
+    fn from_attribute(value: &str) -> Result<Self, String>;
+}
+
+impl Attribute for () {
+    const KEY: Option<&'static str> = None;
+    const TYPE: &'static str = "string";
+
+    fn to_attribute(&self) -> String {
+        String::new()
+    }
+
+    fn from_attribute(_: &str) -> Result<Self, String> {
+        Ok(())
+    }
+}
+
+impl Attribute for String {
+    const KEY: Option<&'static str> = Some("label");
+    const TYPE: &'static str = "string";
+
+    fn to_attribute(&self) -> String {
+        self.clone()
+    }
+
+    fn from_attribute(value: &str) -> Result<Self, String> {
+        Ok(value.to_owned())
+    }
+}
+
+macro_rules! impl_attribute {
+    ($key:literal, $graphml:literal, $($t:ty),*) => {
+        $(
+            impl Attribute for $t {
+                const KEY: Option<&'static str> = Some($key);
+                const TYPE: &'static str = $graphml;
+
+                fn to_attribute(&self) -> String {
+                    self.to_string()
+                }
+
+                fn from_attribute(value: &str) -> Result<Self, String> {
+                    value
+                        .trim()
+                        .parse()
+                        .map_err(|_| format!("invalid {} value '{}'", stringify!($t), value))
+                }
+            }
+        )*
+    };
+}
+
+impl_attribute!("value", "boolean", bool);
+impl_attribute!("weight", "int", i8, i16, i32, u8, u16);
+impl_attribute!("weight", "long", i64, u32, u64, isize, usize);
+impl_attribute!("weight", "float", f32);
+impl_attribute!("weight", "double", f64);
@@ -1,4 +1,28 @@
+mod bitmatrix;
+mod csr;
+mod dot;
+mod error;
 mod graph;
+mod list;
+mod map;
 mod matrix;
+mod stable;
+mod storage;
+mod traversal;
 
+pub mod algo;
+pub mod io;
+pub mod predicates;
+pub mod unionfind;
+pub mod visit;
+
+pub use crate::bitmatrix::*;
+pub use crate::csr::*;
+pub use crate::error::*;
 pub use crate::graph::*;
+pub use crate::list::*;
+pub use crate::map::*;
+pub use crate::matrix::*;
+pub use crate::stable::*;
+pub use crate::storage::*;
+pub use crate::traversal::*;
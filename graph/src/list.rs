@@ -0,0 +1,226 @@
+use crate::storage::{GraphStorage, GraphStorageMut};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdjacencyList<E> {
+    edges: Vec<Option<(usize, usize, E)>>,
+    free: Vec<usize>,
+    outgoing: Vec<Vec<usize>>,
+    incoming: Vec<Vec<usize>>,
+}
+
+impl<E> AdjacencyList<E> {
+    pub fn new() -> Self {
+        Self {
+            edges: Vec::new(),
+            free: Vec::new(),
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+        }
+    }
+
+    fn find(&self, a: usize, b: usize) -> Option<usize> {
+        self.outgoing
+            .get(a)?
+            .iter()
+            .copied()
+            .find(|&id| matches!(self.edges[id], Some((_, y, _)) if y == b))
+    }
+
+    fn entry(&self, id: usize) -> (usize, usize, &E) {
+        match &self.edges[id] {
+            Some((a, b, e)) => (*a, *b, e),
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<E> Default for AdjacencyList<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> GraphStorage<E> for AdjacencyList<E> {
+    fn node_count(&self) -> usize {
+        self.outgoing.len()
+    }
+
+    fn push_default(&mut self) {
+        self.outgoing.push(Vec::new());
+        self.incoming.push(Vec::new());
+    }
+
+    fn remove(&mut self, index: usize) {
+        assert!(index < self.node_count());
+
+        let shift = |i: usize| if i > index { i - 1 } else { i };
+        let edges = std::mem::take(&mut self.edges);
+
+        self.free.clear();
+        self.outgoing.pop();
+        self.incoming.pop();
+        self.outgoing.iter_mut().for_each(Vec::clear);
+        self.incoming.iter_mut().for_each(Vec::clear);
+
+        edges
+            .into_iter()
+            .flatten()
+            .filter(|(a, b, _)| *a != index && *b != index)
+            .for_each(|(a, b, e)| self.set(shift(a), shift(b), e));
+    }
+
+    fn set(&mut self, a: usize, b: usize, value: E) {
+        assert!(a < self.node_count() && b < self.node_count());
+
+        if let Some(id) = self.find(a, b) {
+            self.edges[id] = Some((a, b, value));
+            return;
+        }
+
+        let id = match self.free.pop() {
+            Some(id) => {
+                self.edges[id] = Some((a, b, value));
+                id
+            }
+            None => {
+                self.edges.push(Some((a, b, value)));
+                self.edges.len() - 1
+            }
+        };
+
+        self.outgoing[a].push(id);
+        self.incoming[b].push(id);
+    }
+
+    fn unset(&mut self, a: usize, b: usize) -> Option<E> {
+        let id = self.find(a, b)?;
+
+        self.outgoing[a].retain(|&x| x != id);
+        self.incoming[b].retain(|&x| x != id);
+        self.free.push(id);
+
+        self.edges[id].take().map(|(_, _, e)| e)
+    }
+
+    fn get(&self, a: usize, b: usize) -> Option<&E> {
+        self.find(a, b).map(|id| self.entry(id).2)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (usize, usize, &E)> + '_> {
+        let iter = self
+            .edges
+            .iter()
+            .filter_map(|edge| edge.as_ref().map(|(a, b, e)| (*a, *b, e)));
+
+        Box::new(iter)
+    }
+
+    fn iter_adjacent(&self, index: usize) -> Box<dyn Iterator<Item = (usize, usize, &E)> + '_> {
+        let outgoing = self.outgoing[index].iter().map(|&id| self.entry(id));
+        let incoming = self.incoming[index]
+            .iter()
+            .map(|&id| self.entry(id))
+            .filter(move |(a, _, _)| *a != index);
+
+        Box::new(outgoing.chain(incoming))
+    }
+
+    fn iter_outgoing(&self, index: usize) -> Box<dyn Iterator<Item = (usize, usize, &E)> + '_> {
+        Box::new(self.outgoing[index].iter().map(|&id| self.entry(id)))
+    }
+
+    fn iter_incoming(&self, index: usize) -> Box<dyn Iterator<Item = (usize, usize, &E)> + '_> {
+        Box::new(self.incoming[index].iter().map(|&id| self.entry(id)))
+    }
+}
+
+impl<E> GraphStorageMut<E> for AdjacencyList<E> {
+    fn get_mut(&mut self, a: usize, b: usize) -> Option<&mut E> {
+        let id = self.find(a, b)?;
+
+        self.edges[id].as_mut().map(|(_, _, e)| e)
+    }
+
+    fn iter_mut(&mut self) -> Box<dyn Iterator<Item = (usize, usize, &mut E)> + '_> {
+        let iter = self
+            .edges
+            .iter_mut()
+            .filter_map(|edge| edge.as_mut().map(|(a, b, e)| (*a, *b, e)));
+
+        Box::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_set() -> AdjacencyList<u8> {
+        let mut list = AdjacencyList::<u8>::new();
+        list.push_default();
+        list.push_default();
+        list.push_default();
+        list.set(0, 1, 1);
+        list.set(1, 2, 12);
+        list.set(2, 0, 20);
+        list.set(1, 1, 11);
+
+        list
+    }
+
+    #[test]
+    fn can_get_and_set() {
+        let mut list = create_set();
+
+        assert_eq!(list.get(0, 1), Some(&1));
+        assert_eq!(list.get(1, 2), Some(&12));
+        assert_eq!(list.get(2, 0), Some(&20));
+        assert_eq!(list.get(1, 0), None);
+
+        list.set(0, 1, 2);
+        assert_eq!(list.get(0, 1), Some(&2));
+        assert_eq!(list.iter().count(), 4);
+    }
+
+    #[test]
+    fn can_unset() {
+        let mut list = create_set();
+
+        assert_eq!(list.unset(1, 2), Some(12));
+        assert_eq!(list.unset(1, 2), None);
+        assert_eq!(list.get(1, 2), None);
+
+        list.set(2, 1, 21);
+        assert_eq!(list.get(2, 1), Some(&21));
+        assert_eq!(list.iter().count(), 4);
+    }
+
+    #[test]
+    fn can_remove() {
+        let mut list = create_set();
+        list.remove(1);
+
+        assert_eq!(list.node_count(), 2);
+        assert_eq!(list.get(1, 0), Some(&20));
+        assert_eq!(list.iter().count(), 1);
+    }
+
+    #[test]
+    fn can_iter_adjacent() {
+        let list = create_set();
+        let adjacent: Vec<_> = list.iter_adjacent(1).collect();
+
+        assert_eq!(adjacent, vec![(1, 2, &12), (1, 1, &11), (0, 1, &1)]);
+    }
+
+    #[test]
+    fn can_iter_outgoing_and_incoming() {
+        let list = create_set();
+
+        let outgoing: Vec<_> = list.iter_outgoing(1).collect();
+        assert_eq!(outgoing, vec![(1, 2, &12), (1, 1, &11)]);
+
+        let incoming: Vec<_> = list.iter_incoming(1).collect();
+        assert_eq!(incoming, vec![(0, 1, &1), (1, 1, &11)]);
+    }
+}
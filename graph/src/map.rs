@@ -0,0 +1,112 @@
+use crate::storage::{GraphStorage, GraphStorageMut};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdjacencyMap<E> {
+    data: HashMap<(usize, usize), E>,
+    size: usize,
+}
+
+impl<E> AdjacencyMap<E> {
+    pub fn new() -> Self {
+        Self {
+            data: HashMap::new(),
+            size: 0,
+        }
+    }
+}
+
+impl<E> Default for AdjacencyMap<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> GraphStorage<E> for AdjacencyMap<E> {
+    fn node_count(&self) -> usize {
+        self.size
+    }
+
+    fn push_default(&mut self) {
+        self.size += 1;
+    }
+
+    fn remove(&mut self, index: usize) {
+        assert!(index < self.size);
+
+        let shift = |i: usize| if i > index { i - 1 } else { i };
+
+        self.data = self
+            .data
+            .drain()
+            .filter(|((a, b), _)| *a != index && *b != index)
+            .map(|((a, b), e)| ((shift(a), shift(b)), e))
+            .collect();
+        self.size -= 1;
+    }
+
+    fn set(&mut self, a: usize, b: usize, value: E) {
+        assert!(a < self.size && b < self.size);
+
+        self.data.insert((a, b), value);
+    }
+
+    fn unset(&mut self, a: usize, b: usize) -> Option<E> {
+        self.data.remove(&(a, b))
+    }
+
+    fn get(&self, a: usize, b: usize) -> Option<&E> {
+        self.data.get(&(a, b))
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (usize, usize, &E)> + '_> {
+        Box::new(self.data.iter().map(|(&(a, b), e)| (a, b, e)))
+    }
+}
+
+impl<E> GraphStorageMut<E> for AdjacencyMap<E> {
+    fn get_mut(&mut self, a: usize, b: usize) -> Option<&mut E> {
+        self.data.get_mut(&(a, b))
+    }
+
+    fn iter_mut(&mut self) -> Box<dyn Iterator<Item = (usize, usize, &mut E)> + '_> {
+        Box::new(self.data.iter_mut().map(|(&(a, b), e)| (a, b, e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_set() -> AdjacencyMap<u8> {
+        let mut map = AdjacencyMap::<u8>::new();
+        map.push_default();
+        map.push_default();
+        map.push_default();
+        map.set(0, 1, 1);
+        map.set(1, 2, 12);
+        map.set(2, 0, 20);
+
+        map
+    }
+
+    #[test]
+    fn can_get_and_set() {
+        let map = create_set();
+
+        assert_eq!(map.get(0, 1), Some(&1));
+        assert_eq!(map.get(1, 2), Some(&12));
+        assert_eq!(map.get(2, 0), Some(&20));
+        assert_eq!(map.get(1, 0), None);
+    }
+
+    #[test]
+    fn can_remove() {
+        let mut map = create_set();
+        map.remove(1);
+
+        assert_eq!(map.node_count(), 2);
+        assert_eq!(map.get(1, 0), Some(&20));
+        assert_eq!(map.iter().count(), 1);
+    }
+}
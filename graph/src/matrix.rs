@@ -1,7 +1,100 @@
+use crate::error::GraphError;
+use crate::storage::{GraphStorage, GraphStorageMut};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MatrixCell<T> {
+    #[default]
+    Empty,
+    Edge(T),
+}
+
+impl<T> MatrixCell<T> {
+    pub fn is_empty(&self) -> bool {
+        match self {
+            MatrixCell::Empty => true,
+            MatrixCell::Edge(_) => false,
+        }
+    }
+
+    #[inline]
+    pub fn is_edge(&self) -> bool {
+        !self.is_empty()
+    }
+
+    pub fn unwrap_ref(&self) -> &T {
+        match self {
+            MatrixCell::Empty => panic!("called `MatrixCell::unwrap_ref()` on an `Empty` value"),
+            MatrixCell::Edge(e) => e,
+        }
+    }
+
+    pub fn as_edge(&self) -> Option<&T> {
+        match self {
+            MatrixCell::Empty => None,
+            MatrixCell::Edge(e) => Some(e),
+        }
+    }
+
+    pub fn as_edge_mut(&mut self) -> Option<&mut T> {
+        match self {
+            MatrixCell::Empty => None,
+            MatrixCell::Edge(e) => Some(e),
+        }
+    }
+
+    pub fn take(&mut self) -> Option<T> {
+        match std::mem::take(self) {
+            MatrixCell::Empty => None,
+            MatrixCell::Edge(e) => Some(e),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "MatrixData<T>"))]
 pub struct AdjacencyMatrix<T> {
     data: Vec<T>,
     size: usize,
+    triangular: bool,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct MatrixData<T> {
+    data: Vec<T>,
+    size: usize,
+    #[serde(default)]
+    triangular: bool,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<MatrixData<T>> for AdjacencyMatrix<T> {
+    type Error = String;
+
+    fn try_from(matrix: MatrixData<T>) -> Result<Self, Self::Error> {
+        let expected = match matrix.triangular {
+            true => Self::triangular_chunk_offset(matrix.size),
+            false => Self::chunk_offset(matrix.size),
+        };
+        if matrix.data.len() != expected {
+            return Err(format!(
+                "matrix of size {} must hold {} cells, found {}",
+                matrix.size,
+                expected,
+                matrix.data.len()
+            ));
+        }
+
+        Ok(Self {
+            data: matrix.data,
+            size: matrix.size,
+            triangular: matrix.triangular,
+        })
+    }
 }
 
 impl<T> AdjacencyMatrix<T> {
@@ -9,14 +102,27 @@ impl<T> AdjacencyMatrix<T> {
         Self {
             data: Vec::new(),
             size: 0,
+            triangular: false,
         }
     }
 
+    pub fn new_triangular() -> Self {
+        Self {
+            data: Vec::new(),
+            size: 0,
+            triangular: true,
+        }
+    }
+
+    pub fn is_triangular(&self) -> bool {
+        self.triangular
+    }
+
     pub fn push_default(&mut self)
     where
         T: Default,
     {
-        let len = Self::chunk_len(self.size);
+        let len = self.len_of(self.size);
         self.data.extend((0..len).map(|_| T::default()));
         self.size += 1;
     }
@@ -26,51 +132,61 @@ impl<T> AdjacencyMatrix<T> {
     }
 
     pub fn get(&self, x: usize, y: usize) -> &T {
-        let (chunk, position) = if x > y { (x, y) } else { (y, x + y) };
-        let offset = Self::chunk_offset(chunk);
-
-        &self.data[offset + position]
+        &self.data[self.position(x, y)]
     }
 
     pub fn get_mut(&mut self, x: usize, y: usize) -> &mut T {
-        let (chunk, position) = if x > y { (x, y) } else { (y, x + y) };
-        let offset = Self::chunk_offset(chunk);
+        let position = self.position(x, y);
 
-        &mut self.data[offset + position]
+        &mut self.data[position]
+    }
+
+    fn position(&self, x: usize, y: usize) -> usize {
+        let (chunk, position) = match (self.triangular, x > y) {
+            (true, true) => (x, y),
+            (true, false) => (y, x),
+            (false, true) => (x, y),
+            (false, false) => (y, x + y),
+        };
+
+        self.offset_of(chunk) + position
+    }
+
+    pub fn try_get(&self, x: usize, y: usize) -> Result<&T, GraphError> {
+        self.check(x, y)?;
+
+        Ok(self.get(x, y))
+    }
+
+    pub fn try_get_mut(&mut self, x: usize, y: usize) -> Result<&mut T, GraphError> {
+        self.check(x, y)?;
+
+        Ok(self.get_mut(x, y))
+    }
+
+    fn check(&self, x: usize, y: usize) -> Result<(), GraphError> {
+        match [x, y].into_iter().find(|&index| index >= self.size) {
+            Some(index) => Err(GraphError::NodeOutOfBounds {
+                index,
+                node_count: self.size,
+            }),
+            None => Ok(()),
+        }
     }
 
     pub fn remove(&mut self, remove: usize) {
         assert!(remove < self.size);
 
-        let new_data = self
-            .data
-            .drain(..)
-            .enumerate()
-            .scan(
-                (0, 0, 0, 0),
-                |(next_chunk, next_offset, offset, chunk), (i, el)| {
-                    if *next_offset == i {
-                        *chunk = *next_chunk;
-                        *next_chunk += 1;
-                        *offset = *next_offset;
-                        *next_offset = Self::chunk_offset(*next_chunk);
-                    }
-
-                    let inner_i = i - *offset;
-                    let chunk = *chunk;
-
-                    Some((chunk, inner_i, el))
-                },
-            )
-            .filter_map(|pos| match pos {
-                (ch, _, _) if ch == remove => None,
-                (ch, inn_i, _) if inn_i == remove && ch > remove => None,
-                (ch, inn_i, _) if inn_i == (2 * remove) + 1 && ch > remove => None,
-                (_, _, el) => Some(el),
-            })
-            .collect();
+        let mut cells = Self::layout(self.size, self.triangular);
+        self.data.retain(|_| {
+            let (x, y) = cells.next().unwrap();
+            x != remove && y != remove
+        });
+        self.size -= 1;
+    }
 
-        self.data = new_data;
+    pub fn size(&self) -> usize {
+        self.size
     }
 
     #[inline]
@@ -83,9 +199,143 @@ impl<T> AdjacencyMatrix<T> {
         chunk.pow(2)
     }
 
-    pub fn iter(&self) -> AdjacencyMatrixIterator<T> {
+    #[inline]
+    pub fn triangular_chunk_len(chunk: usize) -> usize {
+        chunk + 1
+    }
+
+    #[inline]
+    pub fn triangular_chunk_offset(chunk: usize) -> usize {
+        chunk * (chunk + 1) / 2
+    }
+
+    fn len_of(&self, chunk: usize) -> usize {
+        match self.triangular {
+            true => Self::triangular_chunk_len(chunk),
+            false => Self::chunk_len(chunk),
+        }
+    }
+
+    fn offset_of(&self, chunk: usize) -> usize {
+        match self.triangular {
+            true => Self::triangular_chunk_offset(chunk),
+            false => Self::chunk_offset(chunk),
+        }
+    }
+
+    fn coordinates(chunk: usize, position: usize, triangular: bool) -> (usize, usize) {
+        match (position < chunk, triangular) {
+            (true, _) => (chunk, position),
+            (false, true) => (chunk, chunk),
+            (false, false) => (position - chunk, chunk),
+        }
+    }
+
+    fn layout(size: usize, triangular: bool) -> impl Iterator<Item = (usize, usize)> {
+        (0..size).flat_map(move |chunk| {
+            let len = match triangular {
+                true => Self::triangular_chunk_len(chunk),
+                false => Self::chunk_len(chunk),
+            };
+            (0..len).map(move |position| Self::coordinates(chunk, position, triangular))
+        })
+    }
+
+    pub fn iter(&self) -> AdjacencyMatrixIterator<'_, T> {
         AdjacencyMatrixIterator::new(self)
     }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut T)> {
+        Self::layout(self.size, self.triangular)
+            .zip(self.data.iter_mut())
+            .map(|((x, y), value)| (x, y, value))
+    }
+}
+
+impl<T> Default for AdjacencyMatrix<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> GraphStorage<E> for AdjacencyMatrix<MatrixCell<E>> {
+    fn new_undirected() -> Self {
+        Self::new_triangular()
+    }
+
+    fn is_symmetric(&self) -> bool {
+        self.triangular
+    }
+
+    fn node_count(&self) -> usize {
+        self.size
+    }
+
+    fn push_default(&mut self) {
+        AdjacencyMatrix::push_default(self);
+    }
+
+    fn remove(&mut self, index: usize) {
+        AdjacencyMatrix::remove(self, index);
+    }
+
+    fn set(&mut self, a: usize, b: usize, value: E) {
+        AdjacencyMatrix::set(self, a, b, MatrixCell::Edge(value));
+    }
+
+    fn unset(&mut self, a: usize, b: usize) -> Option<E> {
+        self.get_mut(a, b).take()
+    }
+
+    fn get(&self, a: usize, b: usize) -> Option<&E> {
+        AdjacencyMatrix::get(self, a, b).as_edge()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (usize, usize, &E)> + '_> {
+        let iter = AdjacencyMatrix::iter(self).filter_map(|(a, b, cell)| match cell {
+            MatrixCell::Empty => None,
+            MatrixCell::Edge(e) => Some((a, b, e)),
+        });
+
+        Box::new(iter)
+    }
+
+    fn iter_adjacent(&self, index: usize) -> Box<dyn Iterator<Item = (usize, usize, &E)> + '_> {
+        let offset = self.offset_of(index);
+        let triangular = self.triangular;
+        let own_chunk = (0..self.len_of(index)).map(move |position| {
+            let (x, y) = Self::coordinates(index, position, triangular);
+
+            (x, y, &self.data[offset + position])
+        });
+
+        let other_chunks = (index + 1..self.size).flat_map(move |chunk| {
+            let offset = self.offset_of(chunk);
+            let lower = std::iter::once((chunk, index, &self.data[offset + index]));
+            let upper = (!triangular).then(|| (index, chunk, &self.data[offset + chunk + index]));
+
+            lower.chain(upper)
+        });
+
+        let iter = own_chunk
+            .chain(other_chunks)
+            .filter_map(|(a, b, cell)| cell.as_edge().map(|e| (a, b, e)));
+
+        Box::new(iter)
+    }
+}
+
+impl<E> GraphStorageMut<E> for AdjacencyMatrix<MatrixCell<E>> {
+    fn get_mut(&mut self, a: usize, b: usize) -> Option<&mut E> {
+        AdjacencyMatrix::get_mut(self, a, b).as_edge_mut()
+    }
+
+    fn iter_mut(&mut self) -> Box<dyn Iterator<Item = (usize, usize, &mut E)> + '_> {
+        let iter = AdjacencyMatrix::iter_mut(self)
+            .filter_map(|(a, b, cell)| cell.as_edge_mut().map(|e| (a, b, e)));
+
+        Box::new(iter)
+    }
 }
 
 pub struct AdjacencyMatrixIterator<'a, T> {
@@ -111,11 +361,11 @@ impl<'a, T> Iterator for AdjacencyMatrixIterator<'a, T> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let next = if self.index < self.matrix.data.len() {
-            let (x, y) = if self.chunk > self.inner_index {
-                (self.chunk, self.inner_index)
-            } else {
-                (self.inner_index - self.chunk, self.chunk)
-            };
+            let (x, y) = AdjacencyMatrix::<T>::coordinates(
+                self.chunk,
+                self.inner_index,
+                self.matrix.triangular,
+            );
 
             Some((x, y, &self.matrix.data[self.index]))
         } else {
@@ -124,7 +374,7 @@ impl<'a, T> Iterator for AdjacencyMatrixIterator<'a, T> {
 
         self.index += 1;
         self.inner_index += 1;
-        if self.index >= AdjacencyMatrix::<T>::chunk_offset(self.chunk + 1) {
+        if self.index >= self.matrix.offset_of(self.chunk + 1) {
             self.chunk += 1;
             self.inner_index = 0;
         }
@@ -133,13 +383,14 @@ impl<'a, T> Iterator for AdjacencyMatrixIterator<'a, T> {
 }
 
 #[cfg(test)]
+#[allow(clippy::zero_prefixed_literal)]
 mod tests {
     use super::*;
 
     #[test]
     fn can_create() {
         let matrix = AdjacencyMatrix::<u8>::new();
-        assert_eq!(matrix.data, vec![]);
+        assert_eq!(matrix.data, Vec::<u8>::new());
         assert_eq!(matrix.size, 0);
     }
 
@@ -192,6 +443,32 @@ mod tests {
         assert_eq!(matrix.data, vec![00, 20, 02, 22]);
     }
 
+    #[test]
+    fn can_remove_first() {
+        let mut matrix = create_set();
+        matrix.remove(0);
+
+        assert_eq!(matrix.data, vec![11, 21, 12, 22]);
+        assert_eq!(matrix.size, 2);
+    }
+
+    #[test]
+    fn can_iter_mut() {
+        let mut matrix = create_set();
+        let coordinates: Vec<(usize, usize)> = matrix.iter().map(|(x, y, _)| (x, y)).collect();
+
+        matrix
+            .iter_mut()
+            .for_each(|(x, y, value)| *value = (x * 10 + y) as u8);
+
+        assert!(matrix
+            .iter()
+            .map(|(x, y, _)| (x, y))
+            .eq(coordinates.into_iter()));
+        assert_eq!(*matrix.get(2, 1), 21);
+        assert_eq!(*matrix.get(1, 2), 12);
+    }
+
     #[test]
     fn can_iter() {
         let matrix = create_set();
@@ -209,4 +486,147 @@ mod tests {
 
         assert!(matrix.iter().eq(diff.into_iter()));
     }
+
+    #[test]
+    fn can_iter_adjacent() {
+        let mut matrix = AdjacencyMatrix::<MatrixCell<u8>>::new();
+        for _ in 0..4 {
+            matrix.push_default();
+        }
+        GraphStorage::set(&mut matrix, 0, 1, 1);
+        GraphStorage::set(&mut matrix, 2, 1, 21);
+        GraphStorage::set(&mut matrix, 1, 1, 11);
+        GraphStorage::set(&mut matrix, 1, 3, 13);
+        GraphStorage::set(&mut matrix, 3, 2, 32);
+
+        for index in 0..4 {
+            let expected =
+                GraphStorage::iter(&matrix).filter(|(a, b, _)| *a == index || *b == index);
+            assert!(matrix.iter_adjacent(index).eq(expected));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn can_serialize() {
+        let mut matrix = AdjacencyMatrix::<MatrixCell<u8>>::new();
+        matrix.push_default();
+        matrix.push_default();
+        GraphStorage::set(&mut matrix, 1, 0, 10);
+
+        let json = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(
+            json,
+            r#"{"data":["Empty",{"Edge":10},"Empty","Empty"],"size":2,"triangular":false}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<AdjacencyMatrix<MatrixCell<u8>>>(&json).unwrap(),
+            matrix
+        );
+
+        let invalid = r#"{"data":["Empty","Empty"],"size":2}"#;
+        assert!(serde_json::from_str::<AdjacencyMatrix<MatrixCell<u8>>>(invalid).is_err());
+
+        let triangular = r#"{"data":["Empty",{"Edge":10},"Empty"],"size":2,"triangular":true}"#;
+        let matrix = serde_json::from_str::<AdjacencyMatrix<MatrixCell<u8>>>(triangular).unwrap();
+        assert_eq!(GraphStorage::get(&matrix, 1, 0), Some(&10));
+
+        let invalid = r#"{"data":["Empty","Empty","Empty","Empty"],"size":2,"triangular":true}"#;
+        assert!(serde_json::from_str::<AdjacencyMatrix<MatrixCell<u8>>>(invalid).is_err());
+    }
+
+    #[test]
+    fn can_try_get() {
+        let mut matrix = create_set();
+
+        assert_eq!(matrix.try_get(2, 1), Ok(&21));
+        assert_eq!(
+            matrix.try_get(1, 3),
+            Err(GraphError::NodeOutOfBounds {
+                index: 3,
+                node_count: 3
+            })
+        );
+
+        *matrix.try_get_mut(0, 2).unwrap() = 99;
+        assert_eq!(matrix.get(0, 2), &99);
+        assert!(matrix.try_get_mut(3, 0).is_err());
+    }
+
+    fn create_triangular_set() -> AdjacencyMatrix<u8> {
+        let mut matrix = AdjacencyMatrix::<u8>::new_triangular();
+        matrix.push_default();
+        matrix.push_default();
+        matrix.push_default();
+        matrix.set(0, 0, 00);
+        matrix.set(1, 0, 10);
+        matrix.set(1, 1, 11);
+        matrix.set(2, 0, 20);
+        matrix.set(2, 1, 21);
+        matrix.set(2, 2, 22);
+
+        matrix
+    }
+
+    #[test]
+    fn can_store_triangular() {
+        let mut matrix = create_triangular_set();
+
+        assert_eq!(matrix.data, vec![00, 10, 11, 20, 21, 22]);
+        assert_eq!(*matrix.get(0, 2), 20);
+
+        matrix.set(1, 2, 12);
+        assert_eq!(*matrix.get(2, 1), 12);
+        assert_eq!(matrix.data.len(), 6);
+    }
+
+    #[test]
+    fn can_iter_triangular() {
+        let matrix = create_triangular_set();
+        let expected: Vec<(usize, usize, &u8)> = vec![
+            (0, 0, &00),
+            (1, 0, &10),
+            (1, 1, &11),
+            (2, 0, &20),
+            (2, 1, &21),
+            (2, 2, &22),
+        ];
+
+        assert!(matrix.iter().eq(expected.into_iter()));
+
+        let square = create_set();
+        let lower: Vec<(usize, usize, &u8)> = square.iter().filter(|(x, y, _)| x >= y).collect();
+        assert!(matrix.iter().eq(lower.into_iter()));
+    }
+
+    #[test]
+    fn can_remove_triangular() {
+        let mut matrix = create_triangular_set();
+        matrix.remove(1);
+
+        assert_eq!(matrix.data, vec![00, 20, 22]);
+        assert_eq!(matrix.size, 2);
+        assert_eq!(*matrix.get(1, 0), 20);
+
+        matrix.remove(0);
+        assert_eq!(matrix.data, vec![22]);
+    }
+
+    #[test]
+    fn can_iter_adjacent_triangular() {
+        let mut matrix = AdjacencyMatrix::<MatrixCell<u8>>::new_triangular();
+        for _ in 0..4 {
+            matrix.push_default();
+        }
+        GraphStorage::set(&mut matrix, 1, 0, 10);
+        GraphStorage::set(&mut matrix, 2, 1, 21);
+        GraphStorage::set(&mut matrix, 1, 1, 11);
+        GraphStorage::set(&mut matrix, 3, 1, 31);
+
+        for index in 0..4 {
+            let expected =
+                GraphStorage::iter(&matrix).filter(|(a, b, _)| *a == index || *b == index);
+            assert!(matrix.iter_adjacent(index).eq(expected));
+        }
+    }
 }
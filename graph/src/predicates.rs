@@ -0,0 +1,249 @@
+use crate::algo::components::connected_components;
+use crate::graph::Graph;
+use crate::storage::GraphStorage;
+use crate::traversal::{DepthFirstEvents, DfsEvent};
+use crate::unionfind::DisjointSet;
+use crate::visit::{
+    GraphProp, IntoNeighbors, IntoNodeIdentifiers, NodeCount, NodeIndexable, NodeMap, Visitable,
+};
+use std::collections::VecDeque;
+
+pub fn is_cyclic<G>(graph: G) -> bool
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + Visitable + GraphProp,
+{
+    let mut events = match graph.node_identifiers().next() {
+        Some(node) => DepthFirstEvents::new(graph, node),
+        None => return false,
+    };
+
+    graph.node_identifiers().any(|start| {
+        events.move_to(start);
+        events
+            .by_ref()
+            .any(|event| matches!(event, DfsEvent::BackEdge(_, _)))
+    })
+}
+
+pub fn is_connected<G>(graph: G) -> bool
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    connected_components(graph).len() <= 1
+}
+
+// direction is ignored: a directed graph is a forest or a tree
+// when its underlying undirected graph is one
+fn forest_size<G>(graph: G) -> Option<usize>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let mut sets = DisjointSet::new(graph.node_bound());
+    let mut edges = 0;
+    for node in graph.node_identifiers() {
+        let a = graph.to_index(node);
+        for next in graph.neighbors(node) {
+            let b = graph.to_index(next);
+            // an undirected edge is listed from both of its ends
+            if !graph.is_directed() && a < b {
+                continue;
+            }
+            if !sets.union(a, b) {
+                return None;
+            }
+            edges += 1;
+        }
+    }
+
+    Some(edges)
+}
+
+pub fn is_forest<G>(graph: G) -> bool
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    forest_size(graph).is_some()
+}
+
+pub fn is_tree<G>(graph: G) -> bool
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + NodeCount + GraphProp,
+{
+    graph.node_count() > 0 && forest_size(graph) == Some(graph.node_count() - 1)
+}
+
+pub fn bipartition<G>(graph: G) -> Option<G::NodeMap<bool>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + Visitable,
+{
+    // edges are followed both ways, so direction does not split a component
+    let mut adjacent = vec![Vec::new(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let a = graph.to_index(node);
+        for next in graph.neighbors(node) {
+            let b = graph.to_index(next);
+            adjacent[a].push(b);
+            adjacent[b].push(a);
+        }
+    }
+
+    let mut colors: Vec<Option<bool>> = vec![None; graph.node_bound()];
+    let mut queue = VecDeque::new();
+
+    for start in graph.node_identifiers().map(|node| graph.to_index(node)) {
+        if colors[start].is_some() {
+            continue;
+        }
+
+        colors[start] = Some(false);
+        queue.push_back(start);
+        while let Some(index) = queue.pop_front() {
+            let color = colors[index]?;
+            for &next in &adjacent[index] {
+                match colors[next] {
+                    None => {
+                        colors[next] = Some(!color);
+                        queue.push_back(next);
+                    }
+                    Some(other) if other == color => return None,
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    let mut sides = graph.node_map();
+    for node in graph.node_identifiers() {
+        if let Some(color) = colors[graph.to_index(node)] {
+            sides.insert(node, color);
+        }
+    }
+
+    Some(sides)
+}
+
+impl<N, E, S> Graph<N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    pub fn is_cyclic(&self) -> bool {
+        is_cyclic(self)
+    }
+
+    pub fn is_acyclic(&self) -> bool {
+        !self.is_cyclic()
+    }
+
+    pub fn is_connected(&self) -> bool {
+        is_connected(self)
+    }
+
+    pub fn is_forest(&self) -> bool {
+        is_forest(self)
+    }
+
+    pub fn is_tree(&self) -> bool {
+        is_tree(self)
+    }
+
+    pub fn is_bipartite(&self) -> bool {
+        self.bipartition().is_some()
+    }
+
+    pub fn bipartition(&self) -> Option<Vec<bool>> {
+        bipartition(self)?.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+
+    fn path_test_graph(directed: bool) -> Graph<(), ()> {
+        let mut g = match directed {
+            true => Graph::<(), ()>::new_directed(),
+            false => Graph::<(), ()>::new_undirected(),
+        };
+        for _ in 0..4 {
+            g.add_node(());
+        }
+        g.set_edge(0, 1, ());
+        g.set_edge(1, 2, ());
+        g.set_edge(2, 3, ());
+
+        g
+    }
+
+    #[test]
+    fn can_detect_undirected_cycles() {
+        let mut g = path_test_graph(false);
+        assert!(g.is_acyclic());
+        assert!(g.is_tree());
+        assert!(g.is_forest());
+        assert!(g.is_connected());
+
+        g.set_edge(3, 0, ());
+        assert!(g.is_cyclic());
+        assert!(!g.is_tree());
+        assert!(!g.is_forest());
+
+        g.remove_edge(3, 0);
+        g.remove_edge(1, 2);
+        assert!(g.is_forest());
+        assert!(!g.is_tree());
+        assert!(!g.is_connected());
+    }
+
+    #[test]
+    fn can_detect_directed_cycles() {
+        let mut g = path_test_graph(true);
+        g.set_edge(0, 2, ());
+        assert!(g.is_acyclic());
+        assert!(!g.is_forest());
+
+        g.set_edge(3, 1, ());
+        assert!(g.is_cyclic());
+
+        g.remove_edge(3, 1);
+        g.set_edge(2, 2, ());
+        assert!(g.is_cyclic());
+    }
+
+    #[test]
+    fn can_ignore_direction_in_trees() {
+        let mut g = path_test_graph(true);
+        assert!(g.is_tree());
+
+        g.remove_edge(1, 2);
+        g.set_edge(2, 1, ());
+        assert!(g.is_tree());
+        assert!(g.is_forest());
+
+        g.set_edge(1, 2, ());
+        assert!(!g.is_forest());
+        assert!(g.is_cyclic());
+    }
+
+    #[test]
+    fn can_check_bipartite() {
+        let mut g = path_test_graph(false);
+        assert_eq!(g.bipartition(), Some(vec![false, true, false, true]));
+
+        g.set_edge(3, 0, ());
+        assert!(g.is_bipartite());
+
+        g.set_edge(2, 0, ());
+        assert!(!g.is_bipartite());
+        assert_eq!(g.bipartition(), None);
+    }
+
+    #[test]
+    fn can_handle_empty_graph() {
+        let g = Graph::<(), ()>::new_undirected();
+        assert!(g.is_acyclic());
+        assert!(g.is_connected());
+        assert!(g.is_forest());
+        assert!(!g.is_tree());
+        assert_eq!(g.bipartition(), Some(vec![]));
+    }
+}
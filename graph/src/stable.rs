@@ -0,0 +1,364 @@
+use crate::graph::Graph;
+use crate::matrix::{AdjacencyMatrix, MatrixCell};
+use crate::storage::{GraphStorage, GraphStorageMut};
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId {
+    slot: usize,
+    generation: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EdgeId {
+    a: NodeId,
+    b: NodeId,
+    generation: u32,
+}
+
+impl EdgeId {
+    pub fn a(&self) -> NodeId {
+        self.a
+    }
+
+    pub fn b(&self) -> NodeId {
+        self.b
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Slot {
+    generation: u32,
+    index: Option<usize>,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct StableGraph<N, E, S = AdjacencyMatrix<MatrixCell<E>>> {
+    graph: Graph<N, E, S>,
+    slots: Vec<Slot>,
+    ids: Vec<usize>,
+    free: Vec<usize>,
+    edges: HashMap<(NodeId, NodeId), u32>,
+}
+
+impl<N, E, S> StableGraph<N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    pub fn new_directed() -> Self {
+        Self::from_graph(Graph::new_directed())
+    }
+
+    pub fn new_undirected() -> Self {
+        Self::from_graph(Graph::new_undirected())
+    }
+
+    fn from_graph(graph: Graph<N, E, S>) -> Self {
+        Self {
+            graph,
+            slots: Vec::new(),
+            ids: Vec::new(),
+            free: Vec::new(),
+            edges: HashMap::new(),
+        }
+    }
+
+    pub fn graph(&self) -> &Graph<N, E, S> {
+        &self.graph
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    pub fn index(&self, id: NodeId) -> Option<usize> {
+        match self.slots.get(id.slot) {
+            Some(slot) if slot.generation == id.generation => slot.index,
+            _ => None,
+        }
+    }
+
+    pub fn id(&self, index: usize) -> Option<NodeId> {
+        self.ids.get(index).map(|&slot| NodeId {
+            slot,
+            generation: self.slots[slot].generation,
+        })
+    }
+
+    pub fn contains_node(&self, id: NodeId) -> bool {
+        self.index(id).is_some()
+    }
+
+    pub fn add_node(&mut self, value: N) -> NodeId {
+        let index = self.graph.node_count();
+        self.graph.add_node(value);
+
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.slots[slot].index = Some(index);
+                slot
+            }
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    index: Some(index),
+                });
+                self.slots.len() - 1
+            }
+        };
+        self.ids.push(slot);
+
+        NodeId {
+            slot,
+            generation: self.slots[slot].generation,
+        }
+    }
+
+    pub fn node(&self, id: NodeId) -> Option<&N> {
+        self.index(id)
+            .and_then(|index| self.graph.node(index))
+            .map(|n| n.value())
+    }
+
+    pub fn node_mut(&mut self, id: NodeId) -> Option<&mut N> {
+        let index = self.index(id)?;
+
+        self.graph.node_mut(index).map(|n| n.into_value_mut())
+    }
+
+    pub fn set_node(&mut self, id: NodeId, value: N) -> bool {
+        match self.index(id) {
+            Some(index) => {
+                self.graph.set_node(index, value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn remove_node(&mut self, id: NodeId) -> bool {
+        let index = match self.index(id) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        self.graph.remove_node(index);
+        self.ids.remove(index);
+        for &slot in &self.ids[index..] {
+            if let Some(i) = self.slots[slot].index.as_mut() {
+                *i -= 1;
+            }
+        }
+
+        self.edges.retain(|&(a, b), _| a != id && b != id);
+
+        let slot = &mut self.slots[id.slot];
+        slot.index = None;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(id.slot);
+
+        true
+    }
+
+    fn edge_key(&self, a: NodeId, b: NodeId) -> (NodeId, NodeId) {
+        if !self.graph.is_directed() && a.slot > b.slot {
+            (b, a)
+        } else {
+            (a, b)
+        }
+    }
+
+    fn make_edge_id(&self, a: NodeId, b: NodeId) -> EdgeId {
+        let (a, b) = self.edge_key(a, b);
+        let generation = self.edges.get(&(a, b)).copied().unwrap_or(0);
+
+        EdgeId { a, b, generation }
+    }
+
+    fn edge_indices(&self, id: EdgeId) -> Option<(usize, usize)> {
+        let index_a = self.index(id.a)?;
+        let index_b = self.index(id.b)?;
+
+        if self.make_edge_id(id.a, id.b) == id {
+            Some((index_a, index_b))
+        } else {
+            None
+        }
+    }
+
+    pub fn set_edge(&mut self, a: NodeId, b: NodeId, value: E) -> Option<EdgeId> {
+        let index_a = self.index(a)?;
+        let index_b = self.index(b)?;
+        self.graph.set_edge(index_a, index_b, value);
+
+        Some(self.make_edge_id(a, b))
+    }
+
+    pub fn edge_id(&self, a: NodeId, b: NodeId) -> Option<EdgeId> {
+        let index_a = self.index(a)?;
+        let index_b = self.index(b)?;
+
+        self.graph
+            .edge(index_a, index_b)
+            .map(|_| self.make_edge_id(a, b))
+    }
+
+    pub fn edge(&self, id: EdgeId) -> Option<&E> {
+        let (index_a, index_b) = self.edge_indices(id)?;
+
+        self.graph.edge(index_a, index_b).map(|e| e.value())
+    }
+
+    pub fn remove_edge(&mut self, id: EdgeId) -> bool {
+        match self.edge_indices(id) {
+            Some((index_a, index_b)) if self.graph.edge(index_a, index_b).is_some() => {
+                self.graph.remove_edge(index_a, index_b);
+                *self.edges.entry((id.a, id.b)).or_insert(0) += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..self.ids.len()).filter_map(|index| self.id(index))
+    }
+
+    pub fn edge_ids(&self) -> impl Iterator<Item = EdgeId> + '_ {
+        self.graph.edges().filter_map(|e| {
+            Some(self.make_edge_id(self.id(e.a().index())?, self.id(e.b().index())?))
+        })
+    }
+}
+
+impl<N, E, S> StableGraph<N, E, S>
+where
+    S: GraphStorageMut<E>,
+{
+    pub fn edge_mut(&mut self, id: EdgeId) -> Option<&mut E> {
+        let (index_a, index_b) = self.edge_indices(id)?;
+
+        self.graph
+            .edge_mut(index_a, index_b)
+            .map(|e| e.into_value_mut())
+    }
+}
+
+impl<N, E, S> Debug for StableGraph<N, E, S>
+where
+    N: Debug,
+    E: Debug,
+    S: GraphStorage<E>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Ids:")?;
+        f.debug_list().entries(self.node_ids()).finish()?;
+        f.write_str("\n")?;
+        self.graph.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_graph() -> (StableGraph<String, String>, [NodeId; 3]) {
+        let mut g = StableGraph::<String, String>::new_directed();
+        let a = g.add_node("A".to_owned());
+        let b = g.add_node("B".to_owned());
+        let c = g.add_node("C".to_owned());
+
+        g.set_edge(a, b, "AB".to_owned());
+        g.set_edge(b, c, "BC".to_owned());
+        g.set_edge(a, c, "AC".to_owned());
+
+        (g, [a, b, c])
+    }
+
+    #[test]
+    fn can_keep_ids_after_remove() {
+        let (mut g, [a, b, c]) = test_graph();
+
+        assert!(g.remove_node(b));
+        assert_eq!(g.node(a).unwrap(), "A");
+        assert_eq!(g.node(c).unwrap(), "C");
+        assert_eq!(g.node(b), None);
+        assert_eq!(g.index(c), Some(1));
+
+        let ac = g.edge_id(a, c).unwrap();
+        assert_eq!(g.edge(ac).unwrap(), "AC");
+        assert_eq!(g.edge_id(a, b), None);
+        assert_eq!(g.edge_count(), 1);
+
+        g.node_mut(c).unwrap().push('!');
+        g.edge_mut(ac).unwrap().push('!');
+        assert_eq!(g.node(c).unwrap(), "C!");
+        assert_eq!(g.edge(ac).unwrap(), "AC!");
+    }
+
+    #[test]
+    fn can_reject_stale_ids() {
+        let (mut g, [a, b, _]) = test_graph();
+        let ab = g.edge_id(a, b).unwrap();
+
+        g.remove_node(b);
+        let d = g.add_node("D".to_owned());
+
+        assert_ne!(b, d);
+        assert_eq!(g.node(b), None);
+        assert_eq!(g.node(d).unwrap(), "D");
+        assert_eq!(g.edge(ab), None);
+        assert!(!g.remove_node(b));
+        assert!(!g.set_node(b, "X".to_owned()));
+        assert_eq!(g.set_edge(a, b, "AX".to_owned()), None);
+    }
+
+    #[test]
+    fn can_iter_over_ids() {
+        let (mut g, [a, b, c]) = test_graph();
+        g.remove_node(a);
+
+        assert_eq!(g.node_ids().collect::<Vec<_>>(), vec![b, c]);
+        assert_eq!(
+            g.edge_ids().collect::<Vec<_>>(),
+            vec![g.edge_id(b, c).unwrap()]
+        );
+    }
+
+    #[test]
+    fn can_reject_stale_edge_ids() {
+        let (mut g, [a, b, _]) = test_graph();
+        let ab = g.edge_id(a, b).unwrap();
+
+        assert!(g.remove_edge(ab));
+        assert_eq!(g.edge(ab), None);
+        assert!(!g.remove_edge(ab));
+
+        let new_ab = g.set_edge(a, b, "AB2".to_owned()).unwrap();
+        assert_ne!(ab, new_ab);
+        assert_eq!(g.edge(ab), None);
+        assert_eq!(g.edge_mut(ab), None);
+        assert!(!g.remove_edge(ab));
+        assert_eq!(g.edge(new_ab).unwrap(), "AB2");
+        assert_eq!(g.edge_id(a, b), Some(new_ab));
+    }
+
+    #[test]
+    fn can_compare_undirected_edge_ids() {
+        let mut g = StableGraph::<String, String>::new_undirected();
+        let a = g.add_node("A".to_owned());
+        let b = g.add_node("B".to_owned());
+
+        let ab = g.set_edge(b, a, "AB".to_owned()).unwrap();
+        assert_eq!(g.edge_id(a, b), Some(ab));
+        assert_eq!(g.edge_id(b, a), Some(ab));
+        assert_eq!(g.edge_ids().collect::<Vec<_>>(), vec![ab]);
+        assert_eq!(g.set_edge(a, b, "BA".to_owned()), Some(ab));
+        assert_eq!(g.edge(ab).unwrap(), "BA");
+    }
+}
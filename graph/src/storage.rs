@@ -0,0 +1,50 @@
+pub trait GraphStorage<E>: Default {
+    fn new_undirected() -> Self {
+        Self::default()
+    }
+
+    fn is_symmetric(&self) -> bool {
+        false
+    }
+
+    fn node_count(&self) -> usize;
+
+    fn push_default(&mut self);
+
+    fn remove(&mut self, index: usize);
+
+    fn set(&mut self, a: usize, b: usize, value: E);
+
+    fn unset(&mut self, a: usize, b: usize) -> Option<E>;
+
+    fn get(&self, a: usize, b: usize) -> Option<&E>;
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (usize, usize, &E)> + '_>;
+
+    fn iter_adjacent(&self, index: usize) -> Box<dyn Iterator<Item = (usize, usize, &E)> + '_> {
+        Box::new(
+            self.iter()
+                .filter(move |(a, b, _)| *a == index || *b == index),
+        )
+    }
+
+    fn iter_outgoing(&self, index: usize) -> Box<dyn Iterator<Item = (usize, usize, &E)> + '_> {
+        Box::new(
+            self.iter_adjacent(index)
+                .filter(move |(a, _, _)| *a == index),
+        )
+    }
+
+    fn iter_incoming(&self, index: usize) -> Box<dyn Iterator<Item = (usize, usize, &E)> + '_> {
+        Box::new(
+            self.iter_adjacent(index)
+                .filter(move |(_, b, _)| *b == index),
+        )
+    }
+}
+
+pub trait GraphStorageMut<E>: GraphStorage<E> {
+    fn get_mut(&mut self, a: usize, b: usize) -> Option<&mut E>;
+
+    fn iter_mut(&mut self) -> Box<dyn Iterator<Item = (usize, usize, &mut E)> + '_>;
+}
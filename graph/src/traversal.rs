@@ -0,0 +1,496 @@
+use crate::graph::{Graph, NodeRef};
+use crate::matrix::{AdjacencyMatrix, MatrixCell};
+use crate::storage::GraphStorage;
+use crate::visit::{GraphProp, IntoNeighbors, NodeMap, VisitMap, Visitable};
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DfsEvent<K = usize> {
+    Discover(K),
+    TreeEdge(K, K),
+    BackEdge(K, K),
+    ForwardEdge(K, K),
+    CrossEdge(K, K),
+    Finish(K),
+}
+
+struct Frame<K, I> {
+    node: K,
+    parent: Option<K>,
+    neighbors: I,
+}
+
+pub struct DepthFirstEvents<G>
+where
+    G: IntoNeighbors + Visitable,
+{
+    graph: G,
+    stack: Vec<Frame<G::NodeId, G::Neighbors>>,
+    discovered: G::NodeMap<usize>,
+    finished: G::Map,
+    time: usize,
+    pending: Option<DfsEvent<G::NodeId>>,
+}
+
+impl<G> DepthFirstEvents<G>
+where
+    G: IntoNeighbors + Visitable + GraphProp,
+{
+    pub fn new(graph: G, start: G::NodeId) -> Self {
+        let mut events = Self {
+            graph,
+            stack: Vec::new(),
+            discovered: graph.node_map(),
+            finished: graph.visit_map(),
+            time: 0,
+            pending: None,
+        };
+        events.move_to(start);
+
+        events
+    }
+
+    pub fn move_to(&mut self, start: G::NodeId) {
+        if !self.is_discovered(start) {
+            self.discover(start, None);
+            self.pending = Some(DfsEvent::Discover(start));
+        }
+    }
+
+    pub fn is_discovered(&self, node: G::NodeId) -> bool {
+        self.discovered.get(node).is_some()
+    }
+
+    fn discover(&mut self, node: G::NodeId, parent: Option<G::NodeId>) {
+        self.discovered.insert(node, self.time);
+        self.time += 1;
+        self.stack.push(Frame {
+            node,
+            parent,
+            neighbors: self.graph.neighbors(node),
+        });
+    }
+}
+
+impl<G> Iterator for DepthFirstEvents<G>
+where
+    G: IntoNeighbors + Visitable + GraphProp,
+{
+    type Item = DfsEvent<G::NodeId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(event) = self.pending.take() {
+            return Some(event);
+        }
+
+        loop {
+            let frame = self.stack.last_mut()?;
+            let node = frame.node;
+
+            let next = match frame.neighbors.next() {
+                Some(next) => next,
+                None => {
+                    self.stack.pop();
+                    self.finished.visit(node);
+                    return Some(DfsEvent::Finish(node));
+                }
+            };
+
+            if self.discovered.get(next).is_none() {
+                self.discover(next, Some(node));
+                self.pending = Some(DfsEvent::Discover(next));
+                return Some(DfsEvent::TreeEdge(node, next));
+            }
+
+            if !self.graph.is_directed() {
+                if frame.parent == Some(next) {
+                    frame.parent = None;
+                    continue;
+                }
+
+                if self.finished.is_visited(next) {
+                    continue;
+                }
+            }
+
+            return Some(if !self.finished.is_visited(next) {
+                DfsEvent::BackEdge(node, next)
+            } else if self.discovered.get(next) > self.discovered.get(node) {
+                DfsEvent::ForwardEdge(node, next)
+            } else {
+                DfsEvent::CrossEdge(node, next)
+            });
+        }
+    }
+}
+
+pub struct DfsEvents<'a, N, E, S = AdjacencyMatrix<MatrixCell<E>>>
+where
+    S: GraphStorage<E>,
+{
+    events: DepthFirstEvents<&'a Graph<N, E, S>>,
+}
+
+impl<'a, N, E, S> DfsEvents<'a, N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    pub fn new(start: NodeRef<'a, N, E, S>) -> Self {
+        Self {
+            events: DepthFirstEvents::new(start.graph(), start.index()),
+        }
+    }
+
+    pub fn move_to(&mut self, start: NodeRef<'a, N, E, S>) {
+        self.events.move_to(start.index());
+    }
+
+    pub fn is_discovered(&self, index: usize) -> bool {
+        self.events.is_discovered(index)
+    }
+}
+
+impl<'a, N, E, S> Iterator for DfsEvents<'a, N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    type Item = DfsEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.events.next()
+    }
+}
+
+pub struct DepthFirst<G>
+where
+    G: IntoNeighbors + Visitable,
+{
+    graph: G,
+    stack: Vec<G::Neighbors>,
+    discovered: G::Map,
+    pending: Option<G::NodeId>,
+}
+
+impl<G> DepthFirst<G>
+where
+    G: IntoNeighbors + Visitable,
+{
+    pub fn new(graph: G, start: G::NodeId) -> Self {
+        let mut dfs = Self {
+            graph,
+            stack: Vec::new(),
+            discovered: graph.visit_map(),
+            pending: None,
+        };
+        dfs.move_to(start);
+
+        dfs
+    }
+
+    pub fn move_to(&mut self, start: G::NodeId) {
+        if self.discovered.visit(start) {
+            self.stack.push(self.graph.neighbors(start));
+            self.pending = Some(start);
+        }
+    }
+
+    pub fn is_discovered(&self, node: G::NodeId) -> bool {
+        self.discovered.is_visited(node)
+    }
+}
+
+impl<G> Iterator for DepthFirst<G>
+where
+    G: IntoNeighbors + Visitable,
+{
+    type Item = G::NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(node) = self.pending.take() {
+            return Some(node);
+        }
+
+        loop {
+            let neighbors = self.stack.last_mut()?;
+            match neighbors.next() {
+                Some(next) if self.discovered.visit(next) => {
+                    self.stack.push(self.graph.neighbors(next));
+                    return Some(next);
+                }
+                Some(_) => continue,
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+pub struct BreadthFirst<G>
+where
+    G: IntoNeighbors + Visitable,
+{
+    graph: G,
+    queue: VecDeque<G::NodeId>,
+    discovered: G::Map,
+}
+
+impl<G> BreadthFirst<G>
+where
+    G: IntoNeighbors + Visitable,
+{
+    pub fn new(graph: G, start: G::NodeId) -> Self {
+        let mut bfs = Self {
+            graph,
+            queue: VecDeque::new(),
+            discovered: graph.visit_map(),
+        };
+        bfs.move_to(start);
+
+        bfs
+    }
+
+    pub fn move_to(&mut self, start: G::NodeId) {
+        if self.discovered.visit(start) {
+            self.queue.push_back(start);
+        }
+    }
+
+    pub fn is_discovered(&self, node: G::NodeId) -> bool {
+        self.discovered.is_visited(node)
+    }
+}
+
+impl<G> Iterator for BreadthFirst<G>
+where
+    G: IntoNeighbors + Visitable,
+{
+    type Item = G::NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+
+        for next in self.graph.neighbors(node) {
+            if self.discovered.visit(next) {
+                self.queue.push_back(next);
+            }
+        }
+
+        Some(node)
+    }
+}
+
+pub struct Dfs<'a, N, E, S = AdjacencyMatrix<MatrixCell<E>>>
+where
+    S: GraphStorage<E>,
+{
+    walk: DepthFirst<&'a Graph<N, E, S>>,
+}
+
+impl<'a, N, E, S> Dfs<'a, N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    pub fn new(start: NodeRef<'a, N, E, S>) -> Self {
+        Self {
+            walk: DepthFirst::new(start.graph(), start.index()),
+        }
+    }
+
+    pub fn move_to(&mut self, start: NodeRef<'a, N, E, S>) {
+        self.walk.move_to(start.index());
+    }
+}
+
+impl<'a, N, E, S> Iterator for Dfs<'a, N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    type Item = NodeRef<'a, N, E, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.walk.next()?;
+        self.walk.graph.node(index)
+    }
+}
+
+pub struct Bfs<'a, N, E, S = AdjacencyMatrix<MatrixCell<E>>>
+where
+    S: GraphStorage<E>,
+{
+    walk: BreadthFirst<&'a Graph<N, E, S>>,
+}
+
+impl<'a, N, E, S> Bfs<'a, N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    pub fn new(start: NodeRef<'a, N, E, S>) -> Self {
+        Self {
+            walk: BreadthFirst::new(start.graph(), start.index()),
+        }
+    }
+
+    pub fn move_to(&mut self, start: NodeRef<'a, N, E, S>) {
+        self.walk.move_to(start.index());
+    }
+}
+
+impl<'a, N, E, S> Iterator for Bfs<'a, N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    type Item = NodeRef<'a, N, E, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.walk.next()?;
+        self.walk.graph.node(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::visit::ImplicitGraph;
+    use DfsEvent::*;
+
+    fn directed_test_graph() -> Graph<(), ()> {
+        let mut g = Graph::<(), ()>::new_directed();
+        for _ in 0..4 {
+            g.add_node(());
+        }
+        g.set_edge(0, 1, ());
+        g.set_edge(1, 2, ());
+        g.set_edge(2, 0, ());
+        g.set_edge(0, 2, ());
+        g.set_edge(3, 2, ());
+
+        g
+    }
+
+    fn undirected_test_graph() -> Graph<(), ()> {
+        let mut g = Graph::<(), ()>::new_undirected();
+        for _ in 0..5 {
+            g.add_node(());
+        }
+        g.set_edge(0, 1, ());
+        g.set_edge(0, 2, ());
+        g.set_edge(1, 3, ());
+        g.set_edge(2, 3, ());
+        g.set_edge(3, 4, ());
+
+        g
+    }
+
+    #[test]
+    fn can_classify_directed_edges() {
+        let g = directed_test_graph();
+        let mut events = DfsEvents::new(g.node(0).unwrap());
+        let first: Vec<DfsEvent> = events.by_ref().collect();
+        assert_eq!(
+            first,
+            vec![
+                Discover(0),
+                TreeEdge(0, 1),
+                Discover(1),
+                TreeEdge(1, 2),
+                Discover(2),
+                BackEdge(2, 0),
+                Finish(2),
+                Finish(1),
+                ForwardEdge(0, 2),
+                Finish(0),
+            ]
+        );
+
+        events.move_to(g.node(3).unwrap());
+        let second: Vec<DfsEvent> = events.collect();
+        assert_eq!(second, vec![Discover(3), CrossEdge(3, 2), Finish(3)]);
+    }
+
+    #[test]
+    fn can_classify_undirected_edges() {
+        let g = undirected_test_graph();
+        let events: Vec<DfsEvent> = DfsEvents::new(g.node(0).unwrap())
+            .filter(|e| !matches!(e, Discover(_) | Finish(_)))
+            .collect();
+
+        assert_eq!(
+            events,
+            vec![
+                TreeEdge(0, 1),
+                TreeEdge(1, 3),
+                TreeEdge(3, 2),
+                BackEdge(2, 0),
+                TreeEdge(3, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn can_walk_depth_first() {
+        let g = undirected_test_graph();
+        let order: Vec<usize> = Dfs::new(g.node(0).unwrap()).map(|n| n.index()).collect();
+
+        assert_eq!(order, vec![0, 1, 3, 2, 4]);
+    }
+
+    #[test]
+    fn can_walk_breadth_first() {
+        let g = undirected_test_graph();
+        let order: Vec<usize> = Bfs::new(g.node(0).unwrap()).map(|n| n.index()).collect();
+        assert_eq!(order, vec![0, 1, 2, 3, 4]);
+
+        let g = directed_test_graph();
+        let mut bfs = Bfs::new(g.node(1).unwrap());
+        let order: Vec<usize> = bfs.by_ref().map(|n| n.index()).collect();
+        assert_eq!(order, vec![1, 2, 0]);
+
+        bfs.move_to(g.node(3).unwrap());
+        assert_eq!(bfs.next().unwrap().index(), 3);
+        assert_eq!(bfs.next(), None);
+    }
+
+    #[test]
+    fn can_walk_implicit_graph() {
+        let collatz = ImplicitGraph::new(|n: u64| match n % 2 {
+            0 => [(n / 2, ())],
+            _ => [(3 * n + 1, ())],
+        });
+
+        let order: Vec<u64> = BreadthFirst::new(&collatz, 6).collect();
+        assert_eq!(order, vec![6, 3, 10, 5, 16, 8, 4, 2, 1]);
+
+        let order: Vec<u64> = DepthFirst::new(&collatz, 7).take(5).collect();
+        assert_eq!(order, vec![7, 22, 11, 34, 17]);
+    }
+
+    #[test]
+    fn can_classify_implicit_edges() {
+        let path = |n: u32| [n.checked_sub(1), (n < 3).then_some(n + 1)];
+        let undirected =
+            ImplicitGraph::new_undirected(|n| path(n).into_iter().flatten().map(|m| (m, ())));
+        let directed = ImplicitGraph::new(|n| path(n).into_iter().flatten().map(|m| (m, ())));
+
+        let events: Vec<DfsEvent<u32>> = DepthFirstEvents::new(&undirected, 0)
+            .filter(|e| !matches!(e, Discover(_) | Finish(_)))
+            .collect();
+        assert_eq!(events, vec![TreeEdge(0, 1), TreeEdge(1, 2), TreeEdge(2, 3)]);
+
+        let events: Vec<DfsEvent<u32>> = DepthFirstEvents::new(&directed, 0)
+            .filter(|e| matches!(e, BackEdge(_, _)))
+            .collect();
+        assert_eq!(events, vec![BackEdge(1, 0), BackEdge(2, 1), BackEdge(3, 2)]);
+    }
+
+    #[test]
+    fn can_walk_infinite_graph() {
+        let lattice = ImplicitGraph::new(|(x, y): (i32, i32)| [((x + 1, y), ()), ((x, y + 1), ())]);
+        let mut bfs = BreadthFirst::new(&lattice, (0, 0));
+
+        let first: Vec<(i32, i32)> = bfs.by_ref().take(6).collect();
+        assert_eq!(first, vec![(0, 0), (1, 0), (0, 1), (2, 0), (1, 1), (0, 2)]);
+        assert!(bfs.is_discovered((3, 0)));
+        assert!(!bfs.is_discovered((4, 0)));
+    }
+}
@@ -0,0 +1,133 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    count: usize,
+}
+
+impl DisjointSet {
+    pub fn new(size: usize) -> Self {
+        Self {
+            parents: (0..size).collect(),
+            ranks: vec![0; size],
+            count: size,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    pub fn set_count(&self) -> usize {
+        self.count
+    }
+
+    pub fn push(&mut self) -> usize {
+        let index = self.parents.len();
+        self.parents.push(index);
+        self.ranks.push(0);
+        self.count += 1;
+
+        index
+    }
+
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = x;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+
+        if root_a == root_b {
+            return false;
+        }
+
+        match self.ranks[root_a].cmp(&self.ranks[root_b]) {
+            std::cmp::Ordering::Less => self.parents[root_a] = root_b,
+            std::cmp::Ordering::Greater => self.parents[root_b] = root_a,
+            std::cmp::Ordering::Equal => {
+                self.parents[root_b] = root_a;
+                self.ranks[root_a] += 1;
+            }
+        }
+        self.count -= 1;
+
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub fn into_sets(mut self) -> Vec<Vec<usize>> {
+        let mut sets: Vec<Vec<usize>> = Vec::new();
+        let mut labels = vec![usize::MAX; self.len()];
+
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if labels[root] == usize::MAX {
+                labels[root] = sets.len();
+                sets.push(Vec::new());
+            }
+            sets[labels[root]].push(x);
+        }
+
+        sets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_create() {
+        let mut set = DisjointSet::new(3);
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.set_count(), 3);
+        assert!(!set.same_set(0, 1));
+        assert_eq!(set.find(2), 2);
+    }
+
+    #[test]
+    fn can_union() {
+        let mut set = DisjointSet::new(5);
+        assert!(set.union(0, 1));
+        assert!(set.union(3, 4));
+        assert!(set.union(1, 4));
+        assert!(!set.union(0, 3));
+
+        assert!(set.same_set(0, 4));
+        assert!(!set.same_set(0, 2));
+        assert_eq!(set.set_count(), 2);
+
+        assert_eq!(set.push(), 5);
+        assert_eq!(set.set_count(), 3);
+    }
+
+    #[test]
+    fn can_collect_sets() {
+        let mut set = DisjointSet::new(5);
+        set.union(4, 1);
+        set.union(2, 0);
+
+        assert_eq!(set.into_sets(), vec![vec![0, 2], vec![1, 4], vec![3]]);
+    }
+}
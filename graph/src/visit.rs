@@ -0,0 +1,831 @@
+use crate::csr::CsrGraph;
+use crate::graph::Graph;
+use crate::storage::GraphStorage;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+pub trait GraphBase {
+    type NodeId: Copy + Eq + Hash;
+}
+
+pub trait GraphProp: GraphBase {
+    fn is_directed(&self) -> bool;
+}
+
+pub trait NodeCount: GraphBase {
+    fn node_count(&self) -> usize;
+}
+
+pub trait NodeIndexable: GraphBase {
+    fn node_bound(&self) -> usize;
+    fn to_index(&self, node: Self::NodeId) -> usize;
+    fn to_node(&self, index: usize) -> Self::NodeId;
+}
+
+pub trait IntoNodeIdentifiers: GraphBase + Copy {
+    type NodeIdentifiers: Iterator<Item = Self::NodeId>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers;
+}
+
+pub trait IntoNeighbors: GraphBase + Copy {
+    type Neighbors: Iterator<Item = Self::NodeId>;
+
+    fn neighbors(self, node: Self::NodeId) -> Self::Neighbors;
+}
+
+pub trait IntoEdges: IntoNeighbors {
+    type EdgeWeight;
+    type Edges: Iterator<Item = (Self::NodeId, Self::EdgeWeight)>;
+
+    fn edges(self, node: Self::NodeId) -> Self::Edges;
+}
+
+pub trait VisitMap<K> {
+    fn visit(&mut self, node: K) -> bool;
+    fn is_visited(&self, node: K) -> bool;
+}
+
+impl VisitMap<usize> for Vec<bool> {
+    fn visit(&mut self, node: usize) -> bool {
+        !std::mem::replace(&mut self[node], true)
+    }
+
+    fn is_visited(&self, node: usize) -> bool {
+        self[node]
+    }
+}
+
+impl<K> VisitMap<K> for HashSet<K>
+where
+    K: Eq + Hash,
+{
+    fn visit(&mut self, node: K) -> bool {
+        self.insert(node)
+    }
+
+    fn is_visited(&self, node: K) -> bool {
+        self.contains(&node)
+    }
+}
+
+pub trait NodeMap<K, V> {
+    fn get(&self, node: K) -> Option<&V>;
+    fn insert(&mut self, node: K, value: V);
+}
+
+impl<V> NodeMap<usize, V> for Vec<Option<V>> {
+    fn get(&self, node: usize) -> Option<&V> {
+        <[Option<V>]>::get(self, node)?.as_ref()
+    }
+
+    fn insert(&mut self, node: usize, value: V) {
+        assert!(
+            node < self.len(),
+            "node index {} is out of bounds for a graph with {} nodes",
+            node,
+            self.len()
+        );
+        self[node] = Some(value);
+    }
+}
+
+impl<K, V> NodeMap<K, V> for HashMap<K, V>
+where
+    K: Eq + Hash,
+{
+    fn get(&self, node: K) -> Option<&V> {
+        HashMap::get(self, &node)
+    }
+
+    fn insert(&mut self, node: K, value: V) {
+        HashMap::insert(self, node, value);
+    }
+}
+
+pub trait Visitable: GraphBase {
+    type Map: VisitMap<Self::NodeId>;
+    type NodeMap<V>: NodeMap<Self::NodeId, V>;
+
+    fn visit_map(&self) -> Self::Map;
+    fn node_map<V>(&self) -> Self::NodeMap<V>;
+}
+
+impl<N, E, S> GraphBase for &Graph<N, E, S> {
+    type NodeId = usize;
+}
+
+impl<N, E, S> GraphProp for &Graph<N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    fn is_directed(&self) -> bool {
+        Graph::is_directed(self)
+    }
+}
+
+impl<N, E, S> NodeCount for &Graph<N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    fn node_count(&self) -> usize {
+        Graph::node_count(self)
+    }
+}
+
+impl<N, E, S> NodeIndexable for &Graph<N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    fn node_bound(&self) -> usize {
+        Graph::node_count(self)
+    }
+
+    fn to_index(&self, node: usize) -> usize {
+        node
+    }
+
+    fn to_node(&self, index: usize) -> usize {
+        index
+    }
+}
+
+impl<N, E, S> IntoNodeIdentifiers for &Graph<N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    type NodeIdentifiers = Range<usize>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        0..Graph::node_count(self)
+    }
+}
+
+impl<'a, N, E, S> IntoNeighbors for &'a Graph<N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    type Neighbors = Box<dyn Iterator<Item = usize> + 'a>;
+
+    fn neighbors(self, node: usize) -> Self::Neighbors {
+        Box::new(self.outgoing(node).map(|(next, _)| next))
+    }
+}
+
+impl<'a, N, E, S> IntoEdges for &'a Graph<N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    type EdgeWeight = &'a E;
+    type Edges = Box<dyn Iterator<Item = (usize, &'a E)> + 'a>;
+
+    fn edges(self, node: usize) -> Self::Edges {
+        self.outgoing(node)
+    }
+}
+
+impl<N, E, S> Visitable for &Graph<N, E, S>
+where
+    S: GraphStorage<E>,
+{
+    type Map = Vec<bool>;
+    type NodeMap<V> = Vec<Option<V>>;
+
+    fn visit_map(&self) -> Self::Map {
+        vec![false; Graph::node_count(self)]
+    }
+
+    fn node_map<V>(&self) -> Self::NodeMap<V> {
+        (0..Graph::node_count(self)).map(|_| None).collect()
+    }
+}
+
+impl<N, E> GraphBase for &CsrGraph<N, E> {
+    type NodeId = usize;
+}
+
+impl<N, E> GraphProp for &CsrGraph<N, E> {
+    fn is_directed(&self) -> bool {
+        CsrGraph::is_directed(self)
+    }
+}
+
+impl<N, E> NodeCount for &CsrGraph<N, E> {
+    fn node_count(&self) -> usize {
+        CsrGraph::node_count(self)
+    }
+}
+
+impl<N, E> NodeIndexable for &CsrGraph<N, E> {
+    fn node_bound(&self) -> usize {
+        CsrGraph::node_count(self)
+    }
+
+    fn to_index(&self, node: usize) -> usize {
+        node
+    }
+
+    fn to_node(&self, index: usize) -> usize {
+        index
+    }
+}
+
+impl<N, E> IntoNodeIdentifiers for &CsrGraph<N, E> {
+    type NodeIdentifiers = Range<usize>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        0..CsrGraph::node_count(self)
+    }
+}
+
+impl<'a, N, E> IntoNeighbors for &'a CsrGraph<N, E> {
+    type Neighbors = std::iter::Copied<std::slice::Iter<'a, usize>>;
+
+    fn neighbors(self, node: usize) -> Self::Neighbors {
+        CsrGraph::neighbors(self, node).iter().copied()
+    }
+}
+
+impl<'a, N, E> IntoEdges for &'a CsrGraph<N, E> {
+    type EdgeWeight = &'a E;
+    type Edges = Box<dyn Iterator<Item = (usize, &'a E)> + 'a>;
+
+    fn edges(self, node: usize) -> Self::Edges {
+        Box::new(self.out_edges(node))
+    }
+}
+
+impl<N, E> Visitable for &CsrGraph<N, E> {
+    type Map = Vec<bool>;
+    type NodeMap<V> = Vec<Option<V>>;
+
+    fn visit_map(&self) -> Self::Map {
+        vec![false; CsrGraph::node_count(self)]
+    }
+
+    fn node_map<V>(&self) -> Self::NodeMap<V> {
+        (0..CsrGraph::node_count(self)).map(|_| None).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NodeFiltered<G, F> {
+    graph: G,
+    filter: F,
+}
+
+impl<G, F> NodeFiltered<G, F>
+where
+    G: GraphBase,
+    F: Fn(G::NodeId) -> bool,
+{
+    pub fn new(graph: G, filter: F) -> Self {
+        Self { graph, filter }
+    }
+}
+
+impl<G, F> GraphBase for &NodeFiltered<G, F>
+where
+    G: GraphBase,
+{
+    type NodeId = G::NodeId;
+}
+
+impl<G, F> GraphProp for &NodeFiltered<G, F>
+where
+    G: GraphProp,
+{
+    fn is_directed(&self) -> bool {
+        self.graph.is_directed()
+    }
+}
+
+impl<G, F> NodeCount for &NodeFiltered<G, F>
+where
+    G: IntoNodeIdentifiers,
+    F: Fn(G::NodeId) -> bool,
+{
+    fn node_count(&self) -> usize {
+        self.node_identifiers().count()
+    }
+}
+
+impl<G, F> NodeIndexable for &NodeFiltered<G, F>
+where
+    G: NodeIndexable,
+{
+    fn node_bound(&self) -> usize {
+        self.graph.node_bound()
+    }
+
+    fn to_index(&self, node: G::NodeId) -> usize {
+        self.graph.to_index(node)
+    }
+
+    fn to_node(&self, index: usize) -> G::NodeId {
+        self.graph.to_node(index)
+    }
+}
+
+impl<'a, G, F> IntoNodeIdentifiers for &'a NodeFiltered<G, F>
+where
+    G: IntoNodeIdentifiers,
+    F: Fn(G::NodeId) -> bool,
+{
+    type NodeIdentifiers = Box<dyn Iterator<Item = G::NodeId> + 'a>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        Box::new(
+            self.graph
+                .node_identifiers()
+                .filter(move |&node| (self.filter)(node)),
+        )
+    }
+}
+
+impl<'a, G, F> IntoNeighbors for &'a NodeFiltered<G, F>
+where
+    G: IntoNeighbors,
+    F: Fn(G::NodeId) -> bool,
+{
+    type Neighbors = Box<dyn Iterator<Item = G::NodeId> + 'a>;
+
+    fn neighbors(self, node: G::NodeId) -> Self::Neighbors {
+        match (self.filter)(node) {
+            true => Box::new(
+                self.graph
+                    .neighbors(node)
+                    .filter(move |&next| (self.filter)(next)),
+            ),
+            false => Box::new(std::iter::empty()),
+        }
+    }
+}
+
+impl<'a, G, F> IntoEdges for &'a NodeFiltered<G, F>
+where
+    G: IntoEdges,
+    F: Fn(G::NodeId) -> bool,
+{
+    type EdgeWeight = G::EdgeWeight;
+    type Edges = Box<dyn Iterator<Item = (G::NodeId, G::EdgeWeight)> + 'a>;
+
+    fn edges(self, node: G::NodeId) -> Self::Edges {
+        match (self.filter)(node) {
+            true => Box::new(
+                self.graph
+                    .edges(node)
+                    .filter(move |&(next, _)| (self.filter)(next)),
+            ),
+            false => Box::new(std::iter::empty()),
+        }
+    }
+}
+
+impl<G, F> Visitable for &NodeFiltered<G, F>
+where
+    G: Visitable,
+{
+    type Map = G::Map;
+    type NodeMap<V> = G::NodeMap<V>;
+
+    fn visit_map(&self) -> Self::Map {
+        self.graph.visit_map()
+    }
+
+    fn node_map<V>(&self) -> Self::NodeMap<V> {
+        self.graph.node_map()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EdgeFiltered<G, F> {
+    graph: G,
+    filter: F,
+}
+
+impl<G, F> EdgeFiltered<G, F>
+where
+    G: IntoEdges,
+    G::EdgeWeight: Copy,
+    F: Fn(G::NodeId, G::NodeId, G::EdgeWeight) -> bool,
+{
+    pub fn new(graph: G, filter: F) -> Self {
+        Self { graph, filter }
+    }
+}
+
+impl<G, F> GraphBase for &EdgeFiltered<G, F>
+where
+    G: GraphBase,
+{
+    type NodeId = G::NodeId;
+}
+
+impl<G, F> GraphProp for &EdgeFiltered<G, F>
+where
+    G: GraphProp,
+{
+    fn is_directed(&self) -> bool {
+        self.graph.is_directed()
+    }
+}
+
+impl<G, F> NodeCount for &EdgeFiltered<G, F>
+where
+    G: NodeCount,
+{
+    fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+}
+
+impl<G, F> NodeIndexable for &EdgeFiltered<G, F>
+where
+    G: NodeIndexable,
+{
+    fn node_bound(&self) -> usize {
+        self.graph.node_bound()
+    }
+
+    fn to_index(&self, node: G::NodeId) -> usize {
+        self.graph.to_index(node)
+    }
+
+    fn to_node(&self, index: usize) -> G::NodeId {
+        self.graph.to_node(index)
+    }
+}
+
+impl<G, F> IntoNodeIdentifiers for &EdgeFiltered<G, F>
+where
+    G: IntoNodeIdentifiers,
+{
+    type NodeIdentifiers = G::NodeIdentifiers;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.graph.node_identifiers()
+    }
+}
+
+impl<'a, G, F> IntoNeighbors for &'a EdgeFiltered<G, F>
+where
+    G: IntoEdges,
+    G::EdgeWeight: Copy,
+    F: Fn(G::NodeId, G::NodeId, G::EdgeWeight) -> bool,
+{
+    type Neighbors = Box<dyn Iterator<Item = G::NodeId> + 'a>;
+
+    fn neighbors(self, node: G::NodeId) -> Self::Neighbors {
+        Box::new(self.edges(node).map(|(next, _)| next))
+    }
+}
+
+impl<'a, G, F> IntoEdges for &'a EdgeFiltered<G, F>
+where
+    G: IntoEdges,
+    G::EdgeWeight: Copy,
+    F: Fn(G::NodeId, G::NodeId, G::EdgeWeight) -> bool,
+{
+    type EdgeWeight = G::EdgeWeight;
+    type Edges = Box<dyn Iterator<Item = (G::NodeId, G::EdgeWeight)> + 'a>;
+
+    fn edges(self, node: G::NodeId) -> Self::Edges {
+        Box::new(
+            self.graph
+                .edges(node)
+                .filter(move |&(next, e)| (self.filter)(node, next, e)),
+        )
+    }
+}
+
+impl<G, F> Visitable for &EdgeFiltered<G, F>
+where
+    G: Visitable,
+{
+    type Map = G::Map;
+    type NodeMap<V> = G::NodeMap<V>;
+
+    fn visit_map(&self) -> Self::Map {
+        self.graph.visit_map()
+    }
+
+    fn node_map<V>(&self) -> Self::NodeMap<V> {
+        self.graph.node_map()
+    }
+}
+
+pub struct ImplicitGraph<N, F> {
+    edges: F,
+    directed: bool,
+    marker: PhantomData<fn(N)>,
+}
+
+impl<N, F> ImplicitGraph<N, F> {
+    pub fn new(edges: F) -> Self {
+        Self {
+            edges,
+            directed: true,
+            marker: PhantomData,
+        }
+    }
+
+    // the neighbor function has to list every edge from both of its ends
+    pub fn new_undirected(edges: F) -> Self {
+        Self {
+            edges,
+            directed: false,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<N, F> Clone for ImplicitGraph<N, F>
+where
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            edges: self.edges.clone(),
+            directed: self.directed,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<N, F> GraphBase for &ImplicitGraph<N, F>
+where
+    N: Copy + Eq + Hash,
+{
+    type NodeId = N;
+}
+
+impl<N, F> GraphProp for &ImplicitGraph<N, F>
+where
+    N: Copy + Eq + Hash,
+{
+    fn is_directed(&self) -> bool {
+        self.directed
+    }
+}
+
+impl<N, E, F, I> IntoNeighbors for &ImplicitGraph<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: Fn(N) -> I,
+    I: IntoIterator<Item = (N, E)>,
+{
+    type Neighbors = std::iter::Map<I::IntoIter, fn((N, E)) -> N>;
+
+    fn neighbors(self, node: N) -> Self::Neighbors {
+        (self.edges)(node)
+            .into_iter()
+            .map((|(next, _)| next) as fn((N, E)) -> N)
+    }
+}
+
+impl<N, E, F, I> IntoEdges for &ImplicitGraph<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: Fn(N) -> I,
+    I: IntoIterator<Item = (N, E)>,
+{
+    type EdgeWeight = E;
+    type Edges = I::IntoIter;
+
+    fn edges(self, node: N) -> Self::Edges {
+        (self.edges)(node).into_iter()
+    }
+}
+
+impl<N, F> Visitable for &ImplicitGraph<N, F>
+where
+    N: Copy + Eq + Hash,
+{
+    type Map = HashSet<N>;
+    type NodeMap<V> = HashMap<N, V>;
+
+    fn visit_map(&self) -> Self::Map {
+        HashSet::new()
+    }
+
+    fn node_map<V>(&self) -> Self::NodeMap<V> {
+        HashMap::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::biconnected::{articulation_points, bridges};
+    use crate::algo::components::{connected_components, kosaraju_scc, tarjan_scc};
+    use crate::algo::dag::{toposort, transitive_closure};
+    use crate::algo::flow::edmonds_karp;
+    use crate::algo::mst::kruskal;
+    use crate::algo::shortest_path::{bellman_ford, bfs, dijkstra};
+    use crate::error::GraphError;
+    use crate::predicates::{bipartition, is_cyclic, is_forest, is_tree};
+    use crate::traversal::BreadthFirst;
+
+    #[derive(Clone, Copy)]
+    struct Ring {
+        size: usize,
+        skip: Option<usize>,
+    }
+
+    impl GraphBase for Ring {
+        type NodeId = usize;
+    }
+
+    impl GraphProp for Ring {
+        fn is_directed(&self) -> bool {
+            true
+        }
+    }
+
+    impl NodeCount for Ring {
+        fn node_count(&self) -> usize {
+            self.size
+        }
+    }
+
+    impl NodeIndexable for Ring {
+        fn node_bound(&self) -> usize {
+            self.size
+        }
+
+        fn to_index(&self, node: usize) -> usize {
+            node
+        }
+
+        fn to_node(&self, index: usize) -> usize {
+            index
+        }
+    }
+
+    impl IntoNodeIdentifiers for Ring {
+        type NodeIdentifiers = Range<usize>;
+
+        fn node_identifiers(self) -> Self::NodeIdentifiers {
+            0..self.size
+        }
+    }
+
+    impl IntoNeighbors for Ring {
+        type Neighbors = std::option::IntoIter<usize>;
+
+        fn neighbors(self, node: usize) -> Self::Neighbors {
+            let next = (node + 1) % self.size;
+            (self.skip != Some(node)).then_some(next).into_iter()
+        }
+    }
+
+    impl Visitable for Ring {
+        type Map = Vec<bool>;
+        type NodeMap<V> = Vec<Option<V>>;
+
+        fn visit_map(&self) -> Self::Map {
+            vec![false; self.size]
+        }
+
+        fn node_map<V>(&self) -> Self::NodeMap<V> {
+            (0..self.size).map(|_| None).collect()
+        }
+    }
+
+    #[test]
+    fn can_visit_graph_neighbors() {
+        let mut g = Graph::<(), u32>::new_directed();
+        for _ in 0..3 {
+            g.add_node(());
+        }
+        g.set_edge(0, 1, 5);
+        g.set_edge(0, 2, 7);
+        g.set_edge(2, 0, 1);
+
+        let mut neighbors: Vec<usize> = (&g).neighbors(0).collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec![1, 2]);
+
+        let edges: Vec<(usize, &u32)> = IntoEdges::edges(&g, 2).collect();
+        assert_eq!(edges, vec![(0, &1)]);
+    }
+
+    #[test]
+    fn can_visit_implicit_neighbors() {
+        let grid = ImplicitGraph::new(|(x, y): (i64, i64)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)].map(|next| (next, 1u32))
+        });
+
+        let neighbors: Vec<(i64, i64)> = (&grid).neighbors((0, 0)).collect();
+        assert_eq!(neighbors, vec![(1, 0), (-1, 0), (0, 1), (0, -1)]);
+        assert_eq!((&grid).edges((5, 5)).map(|(_, w)| w).sum::<u32>(), 4);
+    }
+
+    #[test]
+    fn can_run_algorithms_on_user_types() {
+        let ring = Ring {
+            size: 4,
+            skip: None,
+        };
+        assert_eq!(tarjan_scc(ring), vec![vec![0, 1, 2, 3]]);
+        assert_eq!(toposort(ring).unwrap_err().nodes().len(), 4);
+        assert_eq!(bfs(ring, 2).path_to(1), Some(vec![2, 3, 0, 1]));
+        assert!(is_cyclic(ring));
+        assert_eq!(
+            bipartition(ring),
+            Some(vec![Some(false), Some(true), Some(false), Some(true)])
+        );
+        assert_eq!(kosaraju_scc(ring), vec![vec![0, 1, 2, 3]]);
+        assert!(transitive_closure(ring).get(3, 2));
+        assert!(!is_forest(ring));
+        assert_eq!(bridges(ring), Err(GraphError::DirectedGraph));
+
+        let chain = Ring {
+            size: 4,
+            skip: Some(1),
+        };
+        assert_eq!(toposort(chain), Ok(vec![2, 3, 0, 1]));
+        assert_eq!(tarjan_scc(chain).len(), 4);
+        let order: Vec<usize> = BreadthFirst::new(chain, 3).collect();
+        assert_eq!(order, vec![3, 0, 1]);
+        assert!(!is_cyclic(chain));
+        assert!(is_tree(chain));
+        assert!(!transitive_closure(chain).get(1, 2));
+    }
+
+    #[test]
+    fn can_run_algorithms_on_frozen_graph() {
+        let mut g = Graph::<(), u32>::new_directed();
+        for _ in 0..5 {
+            g.add_node(());
+        }
+        for (a, b, w) in [
+            (0, 1, 4),
+            (1, 2, 1),
+            (2, 0, 2),
+            (2, 3, 7),
+            (3, 4, 1),
+            (0, 3, 9),
+        ] {
+            g.set_edge(a, b, w);
+        }
+        let csr = g.clone().freeze();
+
+        assert_eq!(tarjan_scc(&csr), tarjan_scc(&g));
+        assert_eq!(tarjan_scc(&csr), vec![vec![4], vec![3], vec![0, 1, 2]]);
+        assert_eq!(
+            dijkstra(&csr, 0, |w| *w).distance(4),
+            dijkstra(&g, 0, |w| *w).distance(4)
+        );
+        assert_eq!(dijkstra(&csr, 0, |w| *w).path_to(4), Some(vec![0, 3, 4]));
+        assert_eq!(bellman_ford(&csr, 0, |w| *w).unwrap().distance(4), Some(10));
+        assert!(is_cyclic(&csr));
+        assert_eq!(kosaraju_scc(&csr), kosaraju_scc(&g));
+        assert_eq!(
+            edmonds_karp(&csr, 0, 4, |w| *w).unwrap().value(),
+            edmonds_karp(&g, 0, 4, |w| *w).unwrap().value()
+        );
+        assert_eq!(edmonds_karp(&csr, 0, 4, |w| *w).unwrap().value(), 1);
+        assert_eq!(NodeCount::node_count(&&csr), 5);
+    }
+
+    #[test]
+    fn can_filter_nodes_and_edges() {
+        let mut g = Graph::<(), u32>::new_undirected();
+        for _ in 0..5 {
+            g.add_node(());
+        }
+        for (a, b, w) in [(0, 1, 1), (1, 2, 1), (2, 3, 5), (3, 4, 1), (0, 4, 9)] {
+            g.set_edge(a, b, w);
+        }
+
+        let without = NodeFiltered::new(&g, |v| v != 2);
+        assert_eq!(NodeCount::node_count(&&without), 4);
+        assert_eq!(connected_components(&without), vec![vec![0, 1, 3, 4]]);
+        assert_eq!(bfs(&without, 1).path_to(3), Some(vec![1, 0, 4, 3]));
+        assert!(is_cyclic(&g) && !is_cyclic(&without));
+        assert_eq!(bipartition(&g), None);
+        assert_eq!(
+            bipartition(&without),
+            Some(vec![Some(false), Some(true), None, Some(false), Some(true)])
+        );
+
+        let light = EdgeFiltered::new(&g, |_, _, w| *w < 5);
+        assert_eq!(
+            connected_components(&light),
+            vec![vec![0, 1, 2], vec![3, 4]]
+        );
+        assert_eq!(bfs(&light, 0).distance(3), None);
+        assert!(is_forest(&light) && !is_tree(&light));
+
+        assert!(is_tree(&without));
+        assert_eq!(articulation_points(&without), Ok(vec![0, 4]));
+        let mut tree: Vec<(usize, usize, &u32)> = kruskal(&without, |w| *w);
+        tree.sort();
+        assert_eq!(tree, vec![(1, 0, &1), (4, 0, &9), (4, 3, &1)]);
+        assert_eq!(dijkstra(&g, 0, |w| *w).distance(3), Some(7));
+    }
+}
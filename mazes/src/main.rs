@@ -1,7 +1,8 @@
 use clap::Parser;
-use graph::Graph;
+use graph::{
+    algo::shortest_path::bfs, unionfind::DisjointSet, visit::EdgeFiltered, AdjacencyMap, Graph,
+};
 use rand::{seq::SliceRandom, thread_rng};
-use std::cell::Cell;
 
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
@@ -13,6 +14,10 @@ struct Args {
     /// Height of the maze
     #[clap(short, long, default_value_t = 10)]
     height: usize,
+
+    /// Mark the path from the top left to the bottom right corner
+    #[clap(short, long)]
+    solve: bool,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -22,24 +27,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let h = args.height;
 
     // generate grid
-    let graph = {
-        let mut graph: Graph<Cell<usize>, Cell<bool>> = Graph::new_undirected();
+    let mut graph = {
+        let mut graph: Graph<(), bool, AdjacencyMap<bool>> = Graph::new_undirected();
 
-        for i in 0..(w * h) {
-            graph.add_node(Cell::new(i));
+        for _ in 0..(w * h) {
+            graph.add_node(());
         }
 
         for y in 0..h {
             for x in 1..w {
                 let index = y * w + x;
-                graph.set_edge(index, index - 1, Cell::new(false));
+                graph.set_edge(index, index - 1, false);
             }
         }
 
         for y in 1..h {
             for x in 0..w {
                 let index = y * w + x;
-                graph.set_edge(index, index - w, Cell::new(false));
+                graph.set_edge(index, index - w, false);
             }
         }
 
@@ -49,28 +54,56 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // create queue for shuffled edges
     let edge_refs = {
         let mut rng = thread_rng();
-        let mut edges = Vec::from_iter(graph.edges());
+        let mut edges = Vec::from_iter(graph.edges().map(|e| (e.a().index(), e.b().index())));
         edges.shuffle(&mut rng);
 
         edges
     };
 
     // modified Kruskal's algorithm
-    edge_refs.iter().for_each(|e| {
-        let a = e.a().value().get();
-        let b = e.b().value().get();
-
+    let mut groups = DisjointSet::new(graph.node_count());
+    edge_refs.iter().for_each(|&(a, b)| {
         // if edge spans nodes of two different groups,
         // merge those groups and mark the edge
-        if a != b {
-            graph
-                .nodes()
-                .filter(|n| n.value().get() == b)
-                .for_each(|n| n.value().set(a));
-            e.value().set(true);
+        if groups.union(a, b) {
+            *graph.edge_mut(a, b).unwrap().value_mut() = true;
         }
     });
 
+    // the grid is only read from now on
+    let graph = graph.freeze();
+
+    // collect passages, which have to form a spanning tree
+    #[cfg(debug_assertions)]
+    {
+        let mut passages: Graph<(), (), AdjacencyMap<()>> = Graph::new_undirected();
+        graph.nodes().iter().for_each(|_| passages.add_node(()));
+        graph
+            .edges()
+            .filter(|(_, _, e)| **e)
+            .for_each(|(a, b, _)| passages.set_edge(a, b, ()));
+
+        assert!(passages.node_count() == 0 || passages.is_tree());
+    }
+
+    // walk the passages straight from the grid, without building another graph
+    let open = EdgeFiltered::new(&graph, |_, _, open| *open);
+
+    // find path between opposite corners
+    let path = {
+        let mut path = vec![false; graph.node_count()];
+
+        if args.solve && graph.node_count() > 0 {
+            bfs(&open, 0)
+                .path_to(graph.node_count() - 1)
+                .unwrap_or_default()
+                .into_iter()
+                .for_each(|i| path[i] = true);
+        }
+
+        path
+    };
+
     // display result
     let ww = w * 2 + 1;
     let hh = h * 2 + 1;
@@ -88,14 +121,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 // border inner
                 (x, y) if x % 2 == 0 && y % 2 == 0 => print!("█"),
                 // nodes
-                (x, y) if x % 2 == 1 && y % 2 == 1 => print!("░"),
+                (x, y) if x % 2 == 1 && y % 2 == 1 => match path[(y / 2) * w + (x / 2)] {
+                    true => print!("▒"),
+                    false => print!("░"),
+                },
                 // vertical edges
                 (x, y) if y % 2 == 0 => {
                     let a = (y / 2 - 1) * w + (x / 2);
                     let b = a + w;
-                    let e = graph.edge(a, b).map(|x| x.value().get());
+                    let e = graph.edge(a, b).copied();
                     match e {
                         Some(false) => print!("█"),
+                        Some(_) if path[a] && path[b] => print!("▒"),
                         Some(_) | None => print!("░"),
                     }
                 }
@@ -103,9 +140,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 (x, y) if x % 2 == 0 => {
                     let a = (y / 2) * w + (x / 2 - 1);
                     let b = a + 1;
-                    let e = graph.edge(a, b).map(|x| x.value().get());
+                    let e = graph.edge(a, b).copied();
                     match e {
                         Some(false) => print!("█"),
+                        Some(_) if path[a] && path[b] => print!("▒"),
                         Some(_) | None => print!("░"),
                     }
                 }